@@ -0,0 +1,129 @@
+//! Attribute macros for [axum-mcp](https://docs.rs/axum-mcp).
+//!
+//! Enable them with the `macros` feature of `axum-mcp`, which re-exports
+//! [`mcp_tool`], [`mcp_resource`], and [`mcp_prompt`]. Each macro keeps the
+//! annotated function as it is and generates a unit struct implementing the
+//! matching trait, named after the function: `search_arxiv` becomes
+//! `SearchArxivTool`, `SearchArxivResource`, or `SearchArxivPrompt`.
+//!
+//! Descriptions come from the function's doc comment, and parameter
+//! descriptions from its `# Arguments` section (the format understood by
+//! `axum_mcp::schema`). Schemas are derived from the signature at compile
+//! time; unsupported parameter types are compile errors.
+
+use proc_macro::TokenStream;
+
+mod parse;
+mod prompt;
+mod resource;
+mod tool;
+mod types;
+
+/// Turn a function into an MCP tool.
+///
+/// Generates `<Name>Tool`, implementing `axum_mcp::Tool`, with a `NAME`
+/// constant holding the tool name (the function name unless overridden).
+///
+/// Parameters may be `String`, `bool`, integer and float types, `Vec<T>`,
+/// `serde_json::Value`, or `Option` of any of these for optional arguments.
+/// A `&RequestContext` parameter receives the context of the call. The
+/// function may be `async`, and must return `Result<T, E>` where `T:
+/// Serialize` and `E: Into<ToolError>` (such as `String` or `ToolError`).
+///
+/// # Arguments
+///
+/// * `name` - Tool name, instead of the function name
+/// * `description` - Description, instead of the doc comment
+///
+/// # Example
+///
+/// ```ignore
+/// use axum_mcp::{mcp_tool, McpServer};
+///
+/// /// Search arXiv for papers.
+/// ///
+/// /// # Arguments
+/// /// * `query` - Search terms
+/// /// * `limit` - Maximum number of results
+/// #[mcp_tool]
+/// async fn search(query: String, limit: Option<u32>) -> Result<Vec<String>, String> {
+///     Ok(vec![format!("{} ({})", query, limit.unwrap_or(10))])
+/// }
+///
+/// let server = McpServer::new().tool(SearchTool::NAME, SearchTool)?;
+/// ```
+#[proc_macro_attribute]
+pub fn mcp_tool(args: TokenStream, item: TokenStream) -> TokenStream {
+    tool::expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Turn a function without parameters into an MCP resource.
+///
+/// Generates `<Name>Resource`, implementing `axum_mcp::Resource`, with a
+/// `URI` constant. The function must return `Result<T, E>` where `T:
+/// Into<String>` and `E: Display`.
+///
+/// # Arguments
+///
+/// * `uri` - URI of the resource (required)
+/// * `name` - Display name, instead of the function name
+/// * `description` - Description, instead of the doc comment
+/// * `mime_type` - MIME type of the content (default: `text/plain`)
+///
+/// # Example
+///
+/// ```ignore
+/// use axum_mcp::{mcp_resource, McpServer};
+///
+/// /// Status of the latest CI build.
+/// #[mcp_resource(uri = "ci://status", name = "Build status")]
+/// async fn build_status() -> Result<String, String> {
+///     Ok("passing".to_string())
+/// }
+///
+/// let server = McpServer::new().resource(BuildStatusResource::URI, BuildStatusResource)?;
+/// ```
+#[proc_macro_attribute]
+pub fn mcp_resource(args: TokenStream, item: TokenStream) -> TokenStream {
+    resource::expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Turn a function into an MCP prompt.
+///
+/// Generates `<Name>Prompt`, implementing `axum_mcp::Prompt`, with a `NAME`
+/// constant. Parameters must be `String` (required arguments) or
+/// `Option<String>` (optional ones). The function must return `Result<T, E>`
+/// where `T: Into<String>` and `E: Display`.
+///
+/// # Arguments
+///
+/// * `name` - Prompt name, instead of the function name
+/// * `description` - Description, instead of the doc comment
+///
+/// # Example
+///
+/// ```ignore
+/// use axum_mcp::{mcp_prompt, McpServer};
+///
+/// /// Review code for bugs.
+/// ///
+/// /// # Arguments
+/// /// * `code` - The code to review
+/// /// * `focus` - What to pay attention to
+/// #[mcp_prompt(name = "code_review")]
+/// fn review(code: String, focus: Option<String>) -> Result<String, String> {
+///     Ok(format!("Review this code ({}):\n{}", focus.as_deref().unwrap_or("bugs"), code))
+/// }
+///
+/// let server = McpServer::new().prompt(ReviewPrompt::NAME, ReviewPrompt)?;
+/// ```
+#[proc_macro_attribute]
+pub fn mcp_prompt(args: TokenStream, item: TokenStream) -> TokenStream {
+    prompt::expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
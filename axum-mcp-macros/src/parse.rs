@@ -0,0 +1,314 @@
+//! Parsing shared by the attribute macros: macro arguments, doc comments, and
+//! function signatures.
+
+use proc_macro2::{Span, TokenStream};
+use syn::ext::IdentExt;
+use syn::parse::Parser;
+use syn::{Attribute, Expr, FnArg, Ident, ItemFn, Lit, LitStr, Meta, Pat, ReturnType, Type};
+
+/// Key-value arguments of an attribute macro, e.g. `#[mcp_tool(name = "search")]`.
+#[derive(Default)]
+pub(crate) struct MacroArgs {
+    pub name: Option<LitStr>,
+    pub description: Option<LitStr>,
+    pub uri: Option<LitStr>,
+    pub mime_type: Option<LitStr>,
+}
+
+impl MacroArgs {
+    /// Parse the arguments, accepting only the keys in `allowed`.
+    pub fn parse(tokens: TokenStream, allowed: &[&str]) -> syn::Result<Self> {
+        let mut args = Self::default();
+        let parser = syn::meta::parser(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            if !allowed.contains(&key.as_str()) {
+                return Err(meta.error(format!(
+                    "unknown argument; expected one of: {}",
+                    allowed.join(", ")
+                )));
+            }
+            let value: LitStr = meta.value()?.parse()?;
+            let slot = match key.as_str() {
+                "name" => &mut args.name,
+                "description" => &mut args.description,
+                "uri" => &mut args.uri,
+                _ => &mut args.mime_type,
+            };
+            if slot.is_some() {
+                return Err(meta.error(format!("duplicate argument `{}`", key)));
+            }
+            *slot = Some(value);
+            Ok(())
+        });
+        parser.parse2(tokens)?;
+        Ok(args)
+    }
+}
+
+/// What the doc comments of a function say about it.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Docs {
+    /// Text before the first `# ` heading.
+    pub description: String,
+    /// `(name, description)` of each entry in the `# Arguments` section.
+    pub arguments: Vec<(String, String)>,
+}
+
+impl Docs {
+    /// Collect the `///` comments of an item.
+    pub fn from_attributes(attrs: &[Attribute]) -> Self {
+        let mut text = String::new();
+        for attr in attrs {
+            let Meta::NameValue(meta) = &attr.meta else {
+                continue;
+            };
+            if !meta.path.is_ident("doc") {
+                continue;
+            }
+            if let Expr::Lit(expr) = &meta.value {
+                if let Lit::Str(lit) = &expr.lit {
+                    let value = lit.value();
+                    // `/// text` arrives as " text"
+                    text.push_str(value.strip_prefix(' ').unwrap_or(&value));
+                    text.push('\n');
+                }
+            }
+        }
+        Self::parse(&text)
+    }
+
+    /// Split doc text into the description and the documented arguments.
+    ///
+    /// Arguments use the format understood by `axum_mcp::schema`:
+    /// ``* `name` - Description (type: string)``, where the trailing
+    /// parenthesized annotation is dropped.
+    pub fn parse(text: &str) -> Self {
+        let mut docs = Self::default();
+        let mut description = Vec::new();
+        let mut section: Option<&str> = None;
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if let Some(heading) = trimmed.strip_prefix("# ") {
+                section = Some(heading.trim());
+                continue;
+            }
+            match section {
+                None => description.push(line.trim_end()),
+                Some("Arguments") => {
+                    if let Some(argument) = parse_argument_line(trimmed) {
+                        docs.arguments.push(argument);
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        docs.description = description.join("\n").trim().to_string();
+        docs
+    }
+
+    /// Description of the argument `name`, if documented.
+    pub fn argument(&self, name: &str) -> Option<&str> {
+        self.arguments
+            .iter()
+            .find(|(arg, _)| arg == name)
+            .map(|(_, description)| description.as_str())
+    }
+}
+
+fn parse_argument_line(line: &str) -> Option<(String, String)> {
+    let rest = line
+        .strip_prefix('*')
+        .or_else(|| line.strip_prefix('-'))?
+        .trim_start();
+    let rest = rest.strip_prefix('`')?;
+    let (name, rest) = rest.split_once('`')?;
+    let mut description = rest
+        .trim_start()
+        .trim_start_matches(['-', ':'])
+        .trim()
+        .to_string();
+    if let Some(start) = description.rfind('(') {
+        let annotation = &description[start + 1..];
+        if description.ends_with(')')
+            && (annotation.starts_with("type:") || annotation.starts_with("default:"))
+        {
+            description.truncate(start);
+            description = description.trim_end().to_string();
+        }
+    }
+    Some((name.to_string(), description))
+}
+
+/// The description of the item: the macro argument, else the doc comment.
+pub(crate) fn description(
+    args: &MacroArgs,
+    docs: &Docs,
+    macro_name: &str,
+    span: Span,
+) -> syn::Result<String> {
+    if let Some(description) = &args.description {
+        return Ok(description.value());
+    }
+    if docs.description.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "#[{}] needs a doc comment or `description = \"...\"` to describe it to agents",
+                macro_name
+            ),
+        ));
+    }
+    Ok(docs.description.clone())
+}
+
+/// A parameter of the annotated function.
+pub(crate) struct Param {
+    /// Name as seen by clients (`r#type` becomes `type`).
+    pub name: String,
+    pub ty: Type,
+    pub span: Span,
+}
+
+/// Check that the function can be wrapped and collect its parameters.
+pub(crate) fn function_params(function: &ItemFn, macro_name: &str) -> syn::Result<Vec<Param>> {
+    let sig = &function.sig;
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            format!("#[{}] does not support generic functions", macro_name),
+        ));
+    }
+    let returns_result = match &sig.output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    };
+    if !returns_result {
+        let message = format!("#[{}] functions must return `Result<T, E>`", macro_name);
+        return Err(match &sig.output {
+            ReturnType::Type(_, ty) => syn::Error::new_spanned(ty, message),
+            ReturnType::Default => syn::Error::new_spanned(sig, message),
+        });
+    }
+
+    sig.inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Receiver(receiver) => Err(syn::Error::new_spanned(
+                receiver,
+                format!("#[{}] cannot be used on methods", macro_name),
+            )),
+            FnArg::Typed(arg) => match arg.pat.as_ref() {
+                Pat::Ident(pat) => Ok(Param {
+                    name: pat.ident.unraw().to_string(),
+                    ty: (*arg.ty).clone(),
+                    span: pat.ident.span(),
+                }),
+                pat => Err(syn::Error::new_spanned(
+                    pat,
+                    "parameters must be plain identifiers, since they are named by the schema",
+                )),
+            },
+        })
+        .collect()
+}
+
+/// Reject `# Arguments` entries that do not match a parameter, so the docs
+/// cannot drift from the signature.
+pub(crate) fn check_documented_params(
+    docs: &Docs,
+    params: &[Param],
+    function: &ItemFn,
+) -> syn::Result<()> {
+    for (name, _) in &docs.arguments {
+        if !params.iter().any(|param| &param.name == name) {
+            return Err(syn::Error::new_spanned(
+                &function.sig.ident,
+                format!(
+                    "`# Arguments` documents `{}`, which is not a parameter of `{}`",
+                    name, function.sig.ident
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `search_arxiv` + `Tool` -> `SearchArxivTool`.
+pub(crate) fn struct_ident(function: &Ident, suffix: &str) -> Ident {
+    let name: String = function
+        .unraw()
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    Ident::new(&format!("{}{}", name, suffix), function.span())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_and_arguments() {
+        let docs = Docs::parse(
+            "Search arXiv for papers.\n\nReturns the best matches.\n\n# Arguments\n\
+             * `query` - Search terms (type: string)\n\
+             * `limit` - Maximum results (type: integer, default: 10)\n\n# Errors\n\
+             * `none` - ignored\n",
+        );
+        assert_eq!(
+            docs.description,
+            "Search arXiv for papers.\n\nReturns the best matches."
+        );
+        assert_eq!(
+            docs.arguments,
+            vec![
+                ("query".to_string(), "Search terms".to_string()),
+                ("limit".to_string(), "Maximum results".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_argument_line_variants() {
+        assert_eq!(
+            parse_argument_line("- `path`: File to read (relative)"),
+            Some(("path".to_string(), "File to read (relative)".to_string()))
+        );
+        assert_eq!(parse_argument_line("plain text"), None);
+    }
+
+    #[test]
+    fn test_struct_ident() {
+        let ident = Ident::new("search_arxiv", Span::call_site());
+        assert_eq!(struct_ident(&ident, "Tool").to_string(), "SearchArxivTool");
+        let ident = Ident::new_raw("type", Span::call_site());
+        assert_eq!(struct_ident(&ident, "Prompt").to_string(), "TypePrompt");
+    }
+
+    #[test]
+    fn test_unknown_argument_rejected() {
+        let err = MacroArgs::parse(quote::quote!(title = "x"), &["name"]).err();
+        assert!(err.unwrap().to_string().contains("expected one of: name"));
+    }
+}
@@ -0,0 +1,136 @@
+//! Expansion of `#[mcp_prompt]`.
+
+use crate::parse::{self, Docs, MacroArgs};
+use crate::types::{self, ParamKind};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ItemFn;
+
+pub(crate) fn expand(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = MacroArgs::parse(args, &["name", "description"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let params = parse::function_params(&function, "mcp_prompt")?;
+    let docs = Docs::from_attributes(&function.attrs);
+    parse::check_documented_params(&docs, &params, &function)?;
+
+    let fn_ident = &function.sig.ident;
+    let vis = &function.vis;
+    let struct_ident = parse::struct_ident(fn_ident, "Prompt");
+    let name = args
+        .name
+        .as_ref()
+        .map(|name| name.value())
+        .unwrap_or_else(|| fn_ident.to_string());
+    let description = parse::description(&args, &docs, "mcp_prompt", fn_ident.span())?;
+
+    let mut arguments = Vec::new();
+    let mut bindings = Vec::new();
+    let mut call_args = Vec::new();
+    for (index, param) in params.iter().enumerate() {
+        // Prompt arguments are always strings
+        let optional = match types::classify(&param.ty)? {
+            ParamKind::Argument { schema, optional } if schema["type"] == "string" => optional,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &param.ty,
+                    "prompt arguments must be `String` or `Option<String>`",
+                ))
+            }
+        };
+        let mut argument = serde_json::json!({
+            "name": param.name,
+            "required": !optional,
+        });
+        if let Some(description) = docs.argument(&param.name) {
+            argument["description"] = description.into();
+        }
+        arguments.push(argument);
+
+        let binding = format_ident!("__arg{}", index);
+        let (ty, param_name) = (&param.ty, &param.name);
+        bindings.push(quote! {
+            let #binding: #ty = ::axum_mcp::__private::parse_arg(arguments, #param_name)?;
+        });
+        call_args.push(quote!(#binding));
+    }
+    let arguments = serde_json::Value::Array(arguments).to_string();
+
+    let call = match function.sig.asyncness {
+        Some(_) => quote!(#fn_ident(#(#call_args),*).await),
+        None => quote!(#fn_ident(#(#call_args),*)),
+    };
+    let struct_doc = format!(
+        "MCP prompt generated by `#[mcp_prompt]` from [`{}`].",
+        fn_ident
+    );
+
+    Ok(quote! {
+        #function
+
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #struct_ident;
+
+        impl #struct_ident {
+            /// Name of the prompt, to register it under.
+            #vis const NAME: &'static str = #name;
+        }
+
+        #[::axum_mcp::__private::async_trait]
+        impl ::axum_mcp::Prompt for #struct_ident {
+            fn description(&self) -> &str {
+                #description
+            }
+
+            fn arguments(&self) -> ::axum_mcp::__private::serde_json::Value {
+                ::axum_mcp::__private::serde_json::from_str(#arguments)
+                    .expect("arguments generated by #[mcp_prompt] are valid JSON")
+            }
+
+            async fn render(
+                &self,
+                arguments: &::axum_mcp::__private::serde_json::Value,
+            ) -> ::core::result::Result<::std::string::String, ::std::string::String> {
+                #(#bindings)*
+                #call
+                    .map(::core::convert::Into::into)
+                    .map_err(|e| ::std::string::ToString::to_string(&e))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arguments_must_be_strings() {
+        let err = expand(
+            quote!(),
+            quote! {
+                /// Review code.
+                async fn review(lines: u32) -> Result<String, String> { Ok(String::new()) }
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`String` or `Option<String>`"));
+
+        let expanded = expand(
+            quote!(name = "code_review"),
+            quote! {
+                /// Review code.
+                ///
+                /// # Arguments
+                /// * `code` - The code to review
+                async fn review(code: String, focus: Option<String>) -> Result<String, String> {
+                    Ok(code)
+                }
+            },
+        )
+        .unwrap()
+        .to_string();
+        assert!(expanded.contains("struct ReviewPrompt"));
+        assert!(expanded.contains("\"code_review\""));
+    }
+}
@@ -0,0 +1,109 @@
+//! Expansion of `#[mcp_resource]`.
+
+use crate::parse::{self, Docs, MacroArgs};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ItemFn;
+
+pub(crate) fn expand(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = MacroArgs::parse(args, &["uri", "name", "description", "mime_type"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let params = parse::function_params(&function, "mcp_resource")?;
+    if let Some(param) = params.first() {
+        return Err(syn::Error::new(
+            param.span,
+            "#[mcp_resource] functions take no parameters",
+        ));
+    }
+    let fn_ident = &function.sig.ident;
+    let Some(uri) = &args.uri else {
+        return Err(syn::Error::new(
+            fn_ident.span(),
+            "#[mcp_resource] needs a `uri = \"...\"` argument",
+        ));
+    };
+
+    let vis = &function.vis;
+    let struct_ident = parse::struct_ident(fn_ident, "Resource");
+    let name = args
+        .name
+        .as_ref()
+        .map(|name| name.value())
+        .unwrap_or_else(|| fn_ident.to_string());
+    let docs = Docs::from_attributes(&function.attrs);
+    let description = parse::description(&args, &docs, "mcp_resource", fn_ident.span())?;
+    let mime_type = args
+        .mime_type
+        .as_ref()
+        .map(|mime_type| mime_type.value())
+        .unwrap_or_else(|| "text/plain".to_string());
+
+    let call = match function.sig.asyncness {
+        Some(_) => quote!(#fn_ident().await),
+        None => quote!(#fn_ident()),
+    };
+    let struct_doc = format!(
+        "MCP resource generated by `#[mcp_resource]` from [`{}`].",
+        fn_ident
+    );
+
+    Ok(quote! {
+        #function
+
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #struct_ident;
+
+        impl #struct_ident {
+            /// URI of the resource, to register it under.
+            #vis const URI: &'static str = #uri;
+        }
+
+        #[::axum_mcp::__private::async_trait]
+        impl ::axum_mcp::Resource for #struct_ident {
+            fn name(&self) -> &str {
+                #name
+            }
+
+            fn description(&self) -> &str {
+                #description
+            }
+
+            fn mime_type(&self) -> &str {
+                #mime_type
+            }
+
+            async fn read(&self) -> ::core::result::Result<::std::string::String, ::std::string::String> {
+                #call
+                    .map(::core::convert::Into::into)
+                    .map_err(|e| ::std::string::ToString::to_string(&e))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_uri_and_no_parameters() {
+        let item = quote! {
+            /// Build status.
+            async fn status() -> Result<String, String> { Ok(String::new()) }
+        };
+        let err = expand(quote!(), item.clone()).unwrap_err();
+        assert!(err.to_string().contains("needs a `uri"));
+        assert!(expand(quote!(uri = "ci://status"), item).is_ok());
+
+        let err = expand(
+            quote!(uri = "ci://status"),
+            quote! {
+                /// Build status.
+                async fn status(branch: String) -> Result<String, String> { Ok(branch) }
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("take no parameters"));
+    }
+}
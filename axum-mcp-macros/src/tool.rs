@@ -0,0 +1,204 @@
+//! Expansion of `#[mcp_tool]`.
+
+use crate::parse::{self, Docs, MacroArgs};
+use crate::types::{self, ParamKind};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ItemFn;
+
+pub(crate) fn expand(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = MacroArgs::parse(args, &["name", "description"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let params = parse::function_params(&function, "mcp_tool")?;
+    let docs = Docs::from_attributes(&function.attrs);
+    parse::check_documented_params(&docs, &params, &function)?;
+
+    let fn_ident = &function.sig.ident;
+    let vis = &function.vis;
+    let struct_ident = parse::struct_ident(fn_ident, "Tool");
+    let name = args
+        .name
+        .as_ref()
+        .map(|name| name.value())
+        .unwrap_or_else(|| fn_ident.to_string());
+    let description = parse::description(&args, &docs, "mcp_tool", fn_ident.span())?;
+
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    let mut bindings = Vec::new();
+    let mut call_args = Vec::new();
+    for (index, param) in params.iter().enumerate() {
+        match types::classify(&param.ty)? {
+            ParamKind::Context => call_args.push(quote!(ctx)),
+            ParamKind::Argument {
+                mut schema,
+                optional,
+            } => {
+                if let Some(description) = docs.argument(&param.name) {
+                    schema["description"] = description.into();
+                }
+                properties.insert(param.name.clone(), schema);
+                if !optional {
+                    required.push(param.name.clone());
+                }
+
+                let binding = format_ident!("__arg{}", index);
+                let (ty, param_name) = (&param.ty, &param.name);
+                bindings.push(quote! {
+                    let #binding: #ty = ::axum_mcp::__private::parse_arg(arguments, #param_name)?;
+                });
+                call_args.push(quote!(#binding));
+            }
+        }
+    }
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+    .to_string();
+
+    let call = match function.sig.asyncness {
+        Some(_) => quote!(#fn_ident(#(#call_args),*).await),
+        None => quote!(#fn_ident(#(#call_args),*)),
+    };
+    let struct_doc = format!("MCP tool generated by `#[mcp_tool]` from [`{}`].", fn_ident);
+
+    Ok(quote! {
+        #function
+
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #struct_ident;
+
+        impl #struct_ident {
+            /// Name of the tool, to register it under.
+            #vis const NAME: &'static str = #name;
+
+            async fn __call(
+                arguments: &::axum_mcp::__private::serde_json::Value,
+                ctx: &::axum_mcp::RequestContext,
+            ) -> ::core::result::Result<::axum_mcp::__private::serde_json::Value, ::axum_mcp::ToolError> {
+                let _ = ctx;
+                #(#bindings)*
+                let output = #call.map_err(::core::convert::Into::<::axum_mcp::ToolError>::into)?;
+                ::axum_mcp::__private::serde_json::to_value(output).map_err(|e| {
+                    ::axum_mcp::ToolError::execution_failed(::std::format!(
+                        "Failed to serialize output: {}",
+                        e
+                    ))
+                })
+            }
+        }
+
+        #[::axum_mcp::__private::async_trait]
+        impl ::axum_mcp::Tool for #struct_ident {
+            fn description(&self) -> &str {
+                #description
+            }
+
+            fn schema(&self) -> ::axum_mcp::__private::serde_json::Value {
+                ::axum_mcp::__private::serde_json::from_str(#schema)
+                    .expect("schema generated by #[mcp_tool] is valid JSON")
+            }
+
+            async fn call_with_context(
+                &self,
+                arguments: &::axum_mcp::__private::serde_json::Value,
+                ctx: &::axum_mcp::RequestContext,
+            ) -> ::core::result::Result<::axum_mcp::__private::serde_json::Value, ::std::string::String> {
+                Ok(Self::__call(arguments, ctx).await?)
+            }
+
+            async fn invoke(
+                &self,
+                arguments: &::axum_mcp::__private::serde_json::Value,
+                ctx: &::axum_mcp::RequestContext,
+            ) -> ::core::result::Result<::axum_mcp::ToolResult, ::axum_mcp::ToolError> {
+                Self::__call(arguments, ctx)
+                    .await
+                    .map(::axum_mcp::ToolResult::from)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(args: TokenStream, item: TokenStream) -> Result<String, String> {
+        expand(args, item)
+            .map(|tokens| tokens.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_schema_from_signature_and_docs() {
+        let expanded = expand_str(
+            quote!(),
+            quote! {
+                /// Search arXiv.
+                ///
+                /// # Arguments
+                /// * `query` - Search terms
+                async fn search(query: String, limit: Option<u32>) -> Result<Vec<String>, String> {
+                    Ok(vec![])
+                }
+            },
+        )
+        .unwrap();
+        assert!(expanded.contains("struct SearchTool"));
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "Search terms" },
+                "limit": { "type": "integer", "minimum": 0 }
+            },
+            "required": ["query"]
+        })
+        .to_string();
+        let literal = quote!(#schema).to_string();
+        assert!(expanded.contains(&literal), "{}", expanded);
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let cases = [
+            (
+                quote! {
+                    /// Doc.
+                    async fn f(map: HashMap<String, String>) -> Result<(), String> { Ok(()) }
+                },
+                "unsupported parameter type",
+            ),
+            (
+                quote! {
+                    /// Doc.
+                    async fn f(x: u8) -> u8 { x }
+                },
+                "must return `Result<T, E>`",
+            ),
+            (
+                quote! {
+                    async fn f() -> Result<(), String> { Ok(()) }
+                },
+                "needs a doc comment",
+            ),
+            (
+                quote! {
+                    /// Doc.
+                    ///
+                    /// # Arguments
+                    /// * `missing` - Not a parameter
+                    async fn f() -> Result<(), String> { Ok(()) }
+                },
+                "documents `missing`",
+            ),
+        ];
+        for (item, expected) in cases {
+            let err = expand_str(quote!(), item).unwrap_err();
+            assert!(err.contains(expected), "{}", err);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Mapping of Rust parameter types to JSON Schema.
+
+use serde_json::{json, Value};
+use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+const UNSUPPORTED: &str = "unsupported parameter type; expected `String`, `bool`, an integer or \
+                           float type, `Vec<T>`, `Option<T>`, or `serde_json::Value`";
+
+/// How a tool parameter is filled in.
+pub(crate) enum ParamKind {
+    /// Deserialized from the argument of the same name.
+    Argument {
+        /// Schema of the argument.
+        schema: Value,
+        /// Whether the argument may be omitted (`Option<T>`).
+        optional: bool,
+    },
+    /// The `&RequestContext` of the call.
+    Context,
+}
+
+/// Classify a tool parameter by its type.
+pub(crate) fn classify(ty: &Type) -> syn::Result<ParamKind> {
+    match ty {
+        Type::Group(group) => classify(&group.elem),
+        Type::Paren(paren) => classify(&paren.elem),
+        Type::Reference(reference) => match reference.elem.as_ref() {
+            Type::Path(path) if last_ident(path).as_deref() == Some("RequestContext") => {
+                Ok(ParamKind::Context)
+            }
+            Type::Path(path) if last_ident(path).as_deref() == Some("str") => {
+                Err(syn::Error::new_spanned(
+                    ty,
+                    "borrowed parameters are not supported; use `String` instead of `&str`",
+                ))
+            }
+            _ => Err(syn::Error::new_spanned(ty, UNSUPPORTED)),
+        },
+        Type::Path(path) if last_ident(path).as_deref() == Some("Option") => {
+            Ok(ParamKind::Argument {
+                schema: schema(generic_argument(path, ty)?)?,
+                optional: true,
+            })
+        }
+        _ => Ok(ParamKind::Argument {
+            schema: schema(ty)?,
+            optional: false,
+        }),
+    }
+}
+
+/// Schema of a value of type `ty`.
+pub(crate) fn schema(ty: &Type) -> syn::Result<Value> {
+    let path = match ty {
+        Type::Group(group) => return schema(&group.elem),
+        Type::Paren(paren) => return schema(&paren.elem),
+        Type::Path(path) if path.qself.is_none() => path,
+        _ => return Err(syn::Error::new_spanned(ty, UNSUPPORTED)),
+    };
+    Ok(match last_ident(path).unwrap_or_default().as_str() {
+        "String" => json!({ "type": "string" }),
+        "bool" => json!({ "type": "boolean" }),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => json!({ "type": "integer" }),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            json!({ "type": "integer", "minimum": 0 })
+        }
+        "f32" | "f64" => json!({ "type": "number" }),
+        "Vec" => json!({ "type": "array", "items": schema(generic_argument(path, ty)?)? }),
+        // Any JSON value
+        "Value" => json!({}),
+        _ => return Err(syn::Error::new_spanned(ty, UNSUPPORTED)),
+    })
+}
+
+fn last_ident(path: &TypePath) -> Option<String> {
+    path.path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+}
+
+/// The single type argument of `Option<T>` or `Vec<T>`.
+fn generic_argument<'a>(path: &'a TypePath, ty: &Type) -> syn::Result<&'a Type> {
+    if let Some(segment) = path.path.segments.last() {
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let [GenericArgument::Type(inner)] = args.args.iter().collect::<Vec<_>>()[..] {
+                return Ok(inner);
+            }
+        }
+    }
+    Err(syn::Error::new_spanned(ty, UNSUPPORTED))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema_of(ty: &str) -> syn::Result<Value> {
+        schema(&syn::parse_str(ty).unwrap())
+    }
+
+    #[test]
+    fn test_scalar_schemas() {
+        assert_eq!(schema_of("String").unwrap(), json!({ "type": "string" }));
+        assert_eq!(schema_of("bool").unwrap(), json!({ "type": "boolean" }));
+        assert_eq!(schema_of("i64").unwrap(), json!({ "type": "integer" }));
+        assert_eq!(
+            schema_of("u32").unwrap(),
+            json!({ "type": "integer", "minimum": 0 })
+        );
+        assert_eq!(schema_of("f64").unwrap(), json!({ "type": "number" }));
+        assert_eq!(schema_of("serde_json::Value").unwrap(), json!({}));
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            schema_of("Vec<std::string::String>").unwrap(),
+            json!({ "type": "array", "items": { "type": "string" } })
+        );
+        match classify(&syn::parse_str("Option<Vec<f32>>").unwrap()).unwrap() {
+            ParamKind::Argument { schema, optional } => {
+                assert!(optional);
+                assert_eq!(
+                    schema,
+                    json!({ "type": "array", "items": { "type": "number" } })
+                );
+            }
+            ParamKind::Context => panic!("not a context parameter"),
+        }
+        assert!(matches!(
+            classify(&syn::parse_str("&axum_mcp::RequestContext").unwrap()),
+            Ok(ParamKind::Context)
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_types() {
+        for ty in [
+            "HashMap<String, String>",
+            "Option<Option<u8>>",
+            "(u8, u8)",
+            "Vec",
+        ] {
+            let err = classify(&syn::parse_str(ty).unwrap()).err();
+            assert!(
+                err.expect(ty)
+                    .to_string()
+                    .contains("unsupported parameter type"),
+                "{}",
+                ty
+            );
+        }
+        let err = classify(&syn::parse_str("&str").unwrap()).err().unwrap();
+        assert!(err.to_string().contains("use `String`"));
+    }
+}
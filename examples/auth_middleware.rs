@@ -1,14 +1,15 @@
-//! Example showing how to add authentication middleware to an MCP server.
+//! Example showing how to require authentication on an MCP server.
 //!
 //! This demonstrates:
-//! - API key authentication
-//! - Custom middleware
-//! - Protected endpoints
+//! - API key authentication with `ApiKeyAuthenticator`
+//! - Loading hashed keys from a file
+//! - Protected endpoints (everything but `/health`)
+//! - Reading the authenticated caller back in a tool via `RequestContext`
 
-use axum::{extract::Request, http::StatusCode, middleware::Next, response::Response};
-use axum_mcp::{extract_string, McpServer, Tool};
+use axum_mcp::auth::ApiKeyAuthenticator;
+use axum_mcp::{extract_string, McpServer, Principal, RequestContext, Tool};
 use serde_json::Value;
-use std::{collections::HashSet, env};
+use std::env;
 
 use async_trait::async_trait;
 
@@ -34,60 +35,60 @@ impl Tool for ProtectedTool {
         })
     }
 
-    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+    async fn call_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
         let data = extract_string(arguments, "data")?;
+        let caller = ctx
+            .principal()
+            .map(|principal| principal.id.as_str())
+            .unwrap_or("anonymous");
         Ok(serde_json::json!({
-            "processed": format!("Protected: {}", data)
+            "processed": format!("Protected: {}", data),
+            "caller": caller,
+            "request_id": ctx.request_id(),
         }))
     }
 }
 
-/// Authentication middleware
-async fn auth_middleware(request: Request, next: Next) -> Result<Response, StatusCode> {
-    let headers = request.headers();
-    // Get API key from environment or use default for demo
-    let valid_keys: HashSet<String> = env::var("MCP_API_KEYS")
-        .unwrap_or_else(|_| "demo-key-123,test-key-456".to_string())
-        .split(',')
-        .map(|s| s.trim().to_string())
-        .collect();
-
-    // Check for API key in Authorization header
-    let auth_header = headers
-        .get("authorization")
-        .and_then(|v| v.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
-
-    // Support both "Bearer <key>" and just "<key>" formats
-    let api_key = auth_header.strip_prefix("Bearer ").unwrap_or(auth_header);
-
-    if !valid_keys.contains(api_key) {
-        return Err(StatusCode::UNAUTHORIZED);
+/// Load keys once at startup.
+///
+/// `MCP_API_KEYS_FILE` names a key file of `<id> sha256:<hex> [scope ...]`
+/// lines (hash keys with `ApiKeyAuthenticator::hash_key`). Otherwise the
+/// comma-separated plaintext keys in `MCP_API_KEYS` (or demo keys) are hashed
+/// in memory.
+fn authenticator() -> Result<ApiKeyAuthenticator, axum_mcp::McpError> {
+    if let Ok(path) = env::var("MCP_API_KEYS_FILE") {
+        return ApiKeyAuthenticator::from_file(path);
     }
-
-    // Add API key to request extensions for use in handlers
-    // (In a real app, you might extract user info, permissions, etc.)
-    Ok(next.run(request).await)
+    let keys = env::var("MCP_API_KEYS").unwrap_or_else(|_| "demo-key-123,test-key-456".to_string());
+    Ok(keys
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .enumerate()
+        .fold(ApiKeyAuthenticator::new(), |auth, (index, key)| {
+            auth.with_key(Principal::new(format!("api-key-{}", index + 1)), key)
+        }))
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
 
-    // Create server
-    let mut server = McpServer::new();
-    server.register_tool("protected", ProtectedTool)?;
-
-    // Get the router and add auth middleware
-    let app = server
-        .router()
-        .layer(axum::middleware::from_fn(auth_middleware));
+    // Every route but /health now requires a valid key
+    let app = McpServer::new()
+        .tool("protected", ProtectedTool)?
+        .with_authenticator(authenticator()?)
+        .router();
 
     let addr = "127.0.0.1:8080";
     let listener = tokio::net::TcpListener::bind(addr).await?;
 
     tracing::info!("Server running on http://{}", addr);
-    tracing::info!("API keys (set MCP_API_KEYS env var to customize):");
+    tracing::info!("API keys (set MCP_API_KEYS or MCP_API_KEYS_FILE to customize):");
     tracing::info!("  - demo-key-123");
     tracing::info!("  - test-key-456");
     tracing::info!("\nTest with:");
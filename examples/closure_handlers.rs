@@ -0,0 +1,65 @@
+//! Closure-based handlers example.
+//!
+//! The same server as `basic_server.rs`, plus a counter tool sharing state,
+//! with closures instead of a struct and trait impl per handler.
+
+use axum_mcp::{extract_string, extract_string_opt, McpServer};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize tracing
+    tracing_subscriber::fmt::init();
+
+    // Shared state, standing in for a database pool
+    let calls = Arc::new(AtomicU64::new(0));
+
+    let server = McpServer::new()
+        .tool_fn(
+            "echo",
+            "Echo back the input text",
+            json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string", "description": "Text to echo back" }
+                },
+                "required": ["text"]
+            }),
+            |args: Value| async move {
+                let text = extract_string(&args, "text")?;
+                Ok(json!({ "echoed": text }))
+            },
+        )?
+        .tool_fn(
+            "count",
+            "Count how many times this tool was called",
+            json!({ "type": "object" }),
+            move |_args: Value| {
+                let calls = Arc::clone(&calls);
+                async move { Ok(json!(calls.fetch_add(1, Ordering::Relaxed) + 1)) }
+            },
+        )?
+        .resource_fn(
+            "hello://world",
+            "Hello World Resource",
+            "A simple hello world resource",
+            "text/plain",
+            || async { Ok("Hello, World!".to_string()) },
+        )?
+        .prompt_fn(
+            "greeting",
+            "Generate a greeting message",
+            json!([{ "name": "name", "description": "Name to greet", "required": false }]),
+            |args: Value| async move {
+                let name = extract_string_opt(&args, "name").unwrap_or_else(|| "World".to_string());
+                Ok(format!("Hello, {}!", name))
+            },
+        )?;
+
+    // Start server
+    server.serve("127.0.0.1:8080").await?;
+
+    Ok(())
+}
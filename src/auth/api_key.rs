@@ -0,0 +1,256 @@
+//! Static API-key authentication.
+
+use super::{bearer_challenge, bearer_token, AuthError, Authenticator, DEFAULT_REALM};
+use crate::context::Principal;
+use crate::error::McpError;
+use async_trait::async_trait;
+use axum::http::request::Parts;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+use subtle::ConstantTimeEq;
+
+/// Header accepted as an alternative to `Authorization: Bearer <key>`.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Prefix of key hashes in key files and [`ApiKeyAuthenticator::with_key_hash`].
+const HASH_PREFIX: &str = "sha256:";
+
+/// Authenticates requests carrying one of a fixed set of API keys.
+///
+/// Keys are sent as `Authorization: Bearer <key>` or `X-API-Key: <key>`. Only
+/// their SHA-256 hashes are kept, and a presented key is compared against all
+/// of them in constant time. Each key maps to the [`Principal`] handlers see.
+///
+/// Keys can be added in code, or loaded from a file listing one key per line
+/// as `<principal-id> sha256:<hex> [scope ...]`, so that plaintext keys never
+/// need to be stored:
+///
+/// ```text
+/// # id      hash of the key (see ApiKeyAuthenticator::hash_key)     scopes
+/// ci-bot    sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08  tools:read
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_mcp::auth::ApiKeyAuthenticator;
+/// use axum_mcp::{McpServer, Principal};
+///
+/// # fn main() -> Result<(), axum_mcp::McpError> {
+/// let auth = ApiKeyAuthenticator::from_file("/etc/mcp/api-keys")?
+///     .with_key(Principal::new("admin").with_scopes(["admin"]), "s3cr3t-admin-key");
+/// let server = McpServer::new().with_authenticator(auth);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ApiKeyAuthenticator {
+    keys: Vec<ApiKey>,
+    realm: String,
+}
+
+#[derive(Clone)]
+struct ApiKey {
+    hash: [u8; 32],
+    principal: Principal,
+}
+
+impl ApiKeyAuthenticator {
+    /// Create an authenticator that accepts no keys yet.
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            realm: DEFAULT_REALM.to_string(),
+        }
+    }
+
+    /// Accept `key`, authenticating its bearer as `principal` (builder style).
+    ///
+    /// The key is hashed immediately; the plaintext is not kept.
+    pub fn with_key(mut self, principal: impl Into<Principal>, key: impl AsRef<[u8]>) -> Self {
+        self.keys.push(ApiKey {
+            hash: Sha256::digest(key.as_ref()).into(),
+            principal: principal.into(),
+        });
+        self
+    }
+
+    /// Accept the key whose hash is `hash` (builder style).
+    ///
+    /// `hash` is the lowercase or uppercase hex SHA-256 of the key, optionally
+    /// prefixed with `sha256:`, as produced by [`hash_key`](Self::hash_key).
+    pub fn with_key_hash(
+        mut self,
+        principal: impl Into<Principal>,
+        hash: &str,
+    ) -> Result<Self, McpError> {
+        let hash = parse_hash(hash)
+            .ok_or_else(|| McpError::Validation(format!("Invalid API key hash '{}'", hash)))?;
+        self.keys.push(ApiKey {
+            hash,
+            principal: principal.into(),
+        });
+        Ok(self)
+    }
+
+    /// Set the realm named in `WWW-Authenticate` challenges (default `mcp`).
+    pub fn with_realm(mut self, realm: impl Into<String>) -> Self {
+        self.realm = realm.into();
+        self
+    }
+
+    /// Load hashed keys from a key file (see the type-level docs for the format).
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, McpError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse hashed keys in the key-file format.
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    pub fn parse(contents: &str) -> Result<Self, McpError> {
+        let mut authenticator = Self::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (Some(id), Some(hash)) = (fields.next(), fields.next()) else {
+                return Err(McpError::Validation(format!(
+                    "API key line {}: expected '<principal-id> sha256:<hex> [scope ...]'",
+                    index + 1
+                )));
+            };
+            let principal = Principal::new(id).with_scopes(fields);
+            authenticator = authenticator
+                .with_key_hash(principal, hash)
+                .map_err(|e| McpError::Validation(format!("API key line {}: {}", index + 1, e)))?;
+        }
+        Ok(authenticator)
+    }
+
+    /// Hash a key for a key file or [`with_key_hash`](Self::with_key_hash).
+    ///
+    /// Returns `sha256:` followed by the lowercase hex digest.
+    pub fn hash_key(key: impl AsRef<[u8]>) -> String {
+        let digest = Sha256::digest(key.as_ref());
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}{}", HASH_PREFIX, hex)
+    }
+
+    /// Number of accepted keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no keys are accepted.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl Default for ApiKeyAuthenticator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Authenticator for ApiKeyAuthenticator {
+    async fn authenticate(&self, request: &Parts) -> Result<Principal, AuthError> {
+        let key = bearer_token(&request.headers)
+            .or_else(|| {
+                request
+                    .headers
+                    .get(API_KEY_HEADER)
+                    .and_then(|v| v.to_str().ok())
+            })
+            .ok_or(AuthError::MissingCredentials)?;
+        let hash: [u8; 32] = Sha256::digest(key.as_bytes()).into();
+
+        // Compare against every key so the time taken does not depend on which matched
+        let mut matched = None;
+        for api_key in &self.keys {
+            if bool::from(api_key.hash.ct_eq(&hash)) {
+                matched.get_or_insert(&api_key.principal);
+            }
+        }
+        matched
+            .cloned()
+            .ok_or_else(|| AuthError::invalid_credentials("Unknown API key"))
+    }
+
+    fn challenge(&self, error: &AuthError) -> String {
+        bearer_challenge(&self.realm, error)
+    }
+}
+
+impl fmt::Debug for ApiKeyAuthenticator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let principals: Vec<&str> = self
+            .keys
+            .iter()
+            .map(|key| key.principal.id.as_str())
+            .collect();
+        f.debug_struct("ApiKeyAuthenticator")
+            .field("principals", &principals)
+            .field("realm", &self.realm)
+            .finish()
+    }
+}
+
+/// Decode `sha256:<hex>` or bare hex into a digest.
+fn parse_hash(hash: &str) -> Option<[u8; 32]> {
+    let hex = hash.strip_prefix(HASH_PREFIX).unwrap_or(hash).as_bytes();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut digest = [0u8; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.chunks(2)) {
+        let pair = std::str::from_utf8(pair).ok()?;
+        *byte = u8::from_str_radix(pair, 16).ok()?;
+    }
+    Some(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_round_trip() {
+        let hash = ApiKeyAuthenticator::hash_key("test");
+        assert_eq!(
+            hash,
+            "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        );
+        assert_eq!(parse_hash(&hash), Some(Sha256::digest(b"test").into()));
+        assert_eq!(
+            parse_hash(&hash[HASH_PREFIX.len()..].to_uppercase()),
+            parse_hash(&hash)
+        );
+        assert_eq!(parse_hash("sha256:abc"), None);
+        assert_eq!(parse_hash(&"zz".repeat(32)), None);
+    }
+
+    #[test]
+    fn test_parse_key_file() {
+        let auth = ApiKeyAuthenticator::parse(
+            "# comment\n\n\
+             ci-bot sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08 \
+             tools:read tools:call\n",
+        )
+        .unwrap();
+        assert_eq!(auth.keys.len(), 1);
+        assert_eq!(
+            auth.keys[0].principal,
+            Principal::new("ci-bot").with_scopes(["tools:read", "tools:call"])
+        );
+
+        let err = ApiKeyAuthenticator::parse("ci-bot\n").unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+        let err = ApiKeyAuthenticator::parse("\nci-bot sha256:nothex\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+}
@@ -0,0 +1,497 @@
+//! OAuth 2.1 resource-server authentication with JWT access tokens.
+
+use super::{
+    bearer_challenge, bearer_token, quote_safe, AuthError, Authenticator,
+    ProtectedResourceMetadata, DEFAULT_REALM,
+};
+use crate::context::Principal;
+use crate::error::McpError;
+use async_trait::async_trait;
+use axum::http::request::Parts;
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Clock skew tolerated when checking `exp` and `nbf`, unless configured.
+const DEFAULT_LEEWAY: Duration = Duration::from_secs(60);
+
+/// How long keys fetched from a JWKS URL are used before refetching, unless
+/// configured.
+const DEFAULT_JWKS_REFRESH: Duration = Duration::from_secs(600);
+
+/// Minimum time between fetches, so that neither tokens naming unknown key
+/// ids nor an unreachable endpoint cause a fetch per request.
+const MIN_JWKS_REFETCH: Duration = Duration::from_secs(30);
+
+/// Timeout for fetching a JWKS URL.
+const JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Authenticates bearer JWTs issued by an OAuth 2.1 authorization server.
+///
+/// This makes the server an OAuth resource server as described by the MCP
+/// authorization spec. A token is accepted if:
+///
+/// - its signature verifies against a key of the JWKS (selected by `kid`,
+///   or the only key if the token has none), using an asymmetric algorithm
+///   that matches the key
+/// - `iss` is the configured issuer and `aud` contains the configured audience
+/// - it has not expired (`exp`) and is already valid (`nbf`), within the leeway
+/// - it names a subject (`sub`)
+///
+/// The principal's id is `sub`; its scopes come from the space-separated
+/// `scope` claim and the `scp` claim (a string or an array).
+///
+/// Keys come from a JWKS file, an in-memory [`JwkSet`], or the authorization
+/// server's JWKS URL. Keys fetched from a URL are cached and refetched
+/// periodically, and early when a token names an unknown key id so that
+/// rotated keys are picked up.
+///
+/// The authenticator also provides [`ProtectedResourceMetadata`] naming the
+/// audience as the resource and the issuer as its authorization server, which
+/// the router serves at `/.well-known/oauth-protected-resource`; challenges
+/// point clients at it with a `resource_metadata` parameter.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_mcp::auth::JwtAuthenticator;
+/// use axum_mcp::McpServer;
+///
+/// let auth = JwtAuthenticator::new("https://auth.example.com", "https://mcp.example.com/mcp")
+///     .with_jwks_url("https://auth.example.com/.well-known/jwks.json")
+///     .with_scopes_supported(["tools:read", "tools:call"]);
+/// let server = McpServer::new().with_authenticator(auth);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JwtAuthenticator {
+    issuer: String,
+    audience: String,
+    keys: KeySource,
+    jwks_refresh: Duration,
+    leeway: Duration,
+    realm: String,
+    metadata: ProtectedResourceMetadata,
+}
+
+#[derive(Debug, Clone)]
+enum KeySource {
+    Static(Arc<JwkSet>),
+    Remote(Arc<RemoteJwks>),
+}
+
+/// A JWKS URL and the keys last fetched from it.
+#[derive(Debug)]
+struct RemoteJwks {
+    url: String,
+    client: reqwest::Client,
+    cache: Mutex<JwksCache>,
+    /// Held while fetching, so that concurrent misses share one fetch.
+    fetching: tokio::sync::Mutex<()>,
+}
+
+#[derive(Debug, Default)]
+struct JwksCache {
+    /// The keys of the last successful fetch.
+    fetched: Option<FetchedJwks>,
+    /// When a fetch was last attempted, whether or not it succeeded.
+    attempted_at: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct FetchedJwks {
+    keys: Arc<JwkSet>,
+    fetched_at: Instant,
+}
+
+/// The claims read beyond those `jsonwebtoken` validates.
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    scp: Option<ScpClaim>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScpClaim {
+    List(Vec<String>),
+    Joined(String),
+}
+
+impl JwtAuthenticator {
+    /// Accept tokens issued by `issuer` for `audience` (this server's
+    /// canonical URL), once keys are configured.
+    ///
+    /// Until one of the `with_jwks*` methods sets the keys, every token is
+    /// rejected.
+    pub fn new(issuer: impl Into<String>, audience: impl Into<String>) -> Self {
+        let issuer = issuer.into();
+        let audience = audience.into();
+        let metadata = ProtectedResourceMetadata::new(audience.clone())
+            .with_authorization_server(issuer.clone());
+        Self {
+            issuer,
+            audience,
+            keys: KeySource::Static(Arc::new(JwkSet { keys: Vec::new() })),
+            jwks_refresh: DEFAULT_JWKS_REFRESH,
+            leeway: DEFAULT_LEEWAY,
+            realm: DEFAULT_REALM.to_string(),
+            metadata,
+        }
+    }
+
+    /// Verify tokens with the keys of `jwks` (builder style).
+    pub fn with_jwks(mut self, jwks: JwkSet) -> Self {
+        self.keys = KeySource::Static(Arc::new(jwks));
+        self
+    }
+
+    /// Verify tokens with the keys of a JWKS JSON document (builder style).
+    pub fn with_jwks_json(self, json: &str) -> Result<Self, McpError> {
+        let jwks = serde_json::from_str(json)
+            .map_err(|e| McpError::Validation(format!("Invalid JWKS: {}", e)))?;
+        Ok(self.with_jwks(jwks))
+    }
+
+    /// Verify tokens with the keys of a JWKS file (builder style).
+    pub fn with_jwks_file(self, path: impl AsRef<Path>) -> Result<Self, McpError> {
+        self.with_jwks_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Verify tokens with keys fetched from a JWKS URL (builder style).
+    ///
+    /// Keys are fetched on first use. If they cannot be fetched and none were
+    /// fetched before, requests get `503 Service Unavailable`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client's TLS backend cannot be initialized.
+    pub fn with_jwks_url(mut self, url: impl Into<String>) -> Self {
+        self.keys = KeySource::Remote(Arc::new(RemoteJwks {
+            url: url.into(),
+            client: reqwest::Client::new(),
+            cache: Mutex::new(JwksCache::default()),
+            fetching: tokio::sync::Mutex::new(()),
+        }));
+        self
+    }
+
+    /// Set how long keys fetched from a JWKS URL are used before refetching
+    /// (builder style, default 10 minutes).
+    ///
+    /// Has no effect on other key sources.
+    pub fn with_jwks_refresh(mut self, refresh: Duration) -> Self {
+        self.jwks_refresh = refresh;
+        self
+    }
+
+    /// Set the clock skew tolerated for `exp` and `nbf` (builder style,
+    /// default 60 seconds).
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Set the realm named in `WWW-Authenticate` challenges (default `mcp`).
+    pub fn with_realm(mut self, realm: impl Into<String>) -> Self {
+        self.realm = realm.into();
+        self
+    }
+
+    /// Advertise the scopes clients can request (builder style).
+    pub fn with_scopes_supported<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.metadata = self.metadata.with_scopes_supported(scopes);
+        self
+    }
+
+    /// Replace the published protected resource metadata (builder style).
+    ///
+    /// By default it names the audience as the resource and the issuer as
+    /// the authorization server.
+    pub fn with_resource_metadata(mut self, metadata: ProtectedResourceMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Verify `token` and map its claims to a principal.
+    async fn verify(&self, token: &str) -> Result<Principal, AuthError> {
+        let header = jsonwebtoken::decode_header(token)
+            .map_err(|_| AuthError::invalid_credentials("Malformed token"))?;
+        let keys = match &self.keys {
+            KeySource::Static(keys) => Arc::clone(keys),
+            KeySource::Remote(remote) => {
+                remote.get(header.kid.as_deref(), self.jwks_refresh).await?
+            }
+        };
+        let jwk = match header.kid.as_deref() {
+            Some(kid) => keys.find(kid),
+            None if keys.keys.len() == 1 => keys.keys.first(),
+            None => None,
+        }
+        .ok_or_else(|| AuthError::invalid_credentials("Unknown signing key"))?;
+        if !key_accepts(jwk, header.alg) {
+            return Err(AuthError::invalid_credentials(
+                "Signing algorithm does not match the key",
+            ));
+        }
+        let key = DecodingKey::from_jwk(jwk)
+            .map_err(|_| AuthError::invalid_credentials("Unusable signing key"))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        validation.validate_nbf = true;
+        validation.leeway = self.leeway.as_secs();
+
+        let claims = jsonwebtoken::decode::<Claims>(token, &key, &validation)
+            .map_err(|e| AuthError::invalid_credentials(rejection_reason(e.kind())))?
+            .claims;
+        Ok(claims.into_principal())
+    }
+}
+
+#[async_trait]
+impl Authenticator for JwtAuthenticator {
+    async fn authenticate(&self, request: &Parts) -> Result<Principal, AuthError> {
+        let token = bearer_token(&request.headers).ok_or(AuthError::MissingCredentials)?;
+        self.verify(token).await
+    }
+
+    fn challenge(&self, error: &AuthError) -> String {
+        let challenge = bearer_challenge(&self.realm, error);
+        match self.metadata.metadata_url() {
+            Some(url) => format!("{}, resource_metadata=\"{}\"", challenge, quote_safe(&url)),
+            None => challenge,
+        }
+    }
+
+    fn resource_metadata(&self) -> Option<ProtectedResourceMetadata> {
+        Some(self.metadata.clone())
+    }
+}
+
+impl RemoteJwks {
+    /// Return cached keys, refetching them when older than `refresh` or
+    /// missing `kid`.
+    async fn get(&self, kid: Option<&str>, refresh: Duration) -> Result<Arc<JwkSet>, AuthError> {
+        if let Some(cached) = self.cached(kid, refresh) {
+            return cached;
+        }
+
+        // Callers that waited for another fetch use its result
+        let _fetching = self.fetching.lock().await;
+        if let Some(cached) = self.cached(kid, refresh) {
+            return cached;
+        }
+
+        let result = self.fetch().await;
+        let mut cache = self.lock_cache();
+        cache.attempted_at = Some(Instant::now());
+        match result {
+            Ok(keys) => {
+                let keys = Arc::new(keys);
+                cache.fetched = Some(FetchedJwks {
+                    keys: Arc::clone(&keys),
+                    fetched_at: Instant::now(),
+                });
+                Ok(keys)
+            }
+            Err(e) => {
+                tracing::warn!(url = %self.url, "failed to fetch JWKS: {}", e);
+                match &cache.fetched {
+                    Some(fetched) => Ok(Arc::clone(&fetched.keys)),
+                    None => Err(AuthError::Unavailable(format!(
+                        "Failed to fetch JWKS: {}",
+                        e
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// The cache's answer, or `None` if the keys should be fetched.
+    fn cached(
+        &self,
+        kid: Option<&str>,
+        refresh: Duration,
+    ) -> Option<Result<Arc<JwkSet>, AuthError>> {
+        let cache = self.lock_cache();
+        // An unknown kid may mean the keys were rotated, but refetching on
+        // every such token would let callers hammer the JWKS endpoint
+        let throttled = cache
+            .attempted_at
+            .is_some_and(|at| at.elapsed() < MIN_JWKS_REFETCH.min(refresh));
+        match &cache.fetched {
+            Some(fetched) => {
+                let fresh = fetched.fetched_at.elapsed() < refresh
+                    && kid.map_or(true, |kid| fetched.keys.find(kid).is_some());
+                (fresh || throttled).then(|| Ok(Arc::clone(&fetched.keys)))
+            }
+            None => throttled.then(|| {
+                Err(AuthError::Unavailable(
+                    "JWKS could not be fetched".to_string(),
+                ))
+            }),
+        }
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, JwksCache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn fetch(&self) -> Result<JwkSet, String> {
+        let body = self
+            .client
+            .get(&self.url)
+            .timeout(JWKS_FETCH_TIMEOUT)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.to_string())?
+            .bytes()
+            .await
+            .map_err(|e| e.to_string())?;
+        serde_json::from_slice(&body).map_err(|e| format!("Invalid JWKS: {}", e))
+    }
+}
+
+impl Claims {
+    fn into_principal(self) -> Principal {
+        let mut scopes: Vec<String> = self
+            .scope
+            .iter()
+            .flat_map(|scope| scope.split_whitespace())
+            .map(str::to_string)
+            .collect();
+        let scp = match self.scp {
+            Some(ScpClaim::List(list)) => list,
+            Some(ScpClaim::Joined(joined)) => {
+                joined.split_whitespace().map(str::to_string).collect()
+            }
+            None => Vec::new(),
+        };
+        for scope in scp {
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+        Principal::new(self.sub).with_scopes(scopes)
+    }
+}
+
+/// Whether `jwk` may verify a signature made with `alg`.
+///
+/// Only asymmetric algorithms are accepted, and only for keys of their type
+/// (and curve); a key that names its algorithm accepts only that one.
+fn key_accepts(jwk: &Jwk, alg: Algorithm) -> bool {
+    let type_matches = match &jwk.algorithm {
+        AlgorithmParameters::RSA(_) => matches!(
+            alg,
+            Algorithm::RS256
+                | Algorithm::RS384
+                | Algorithm::RS512
+                | Algorithm::PS256
+                | Algorithm::PS384
+                | Algorithm::PS512
+        ),
+        AlgorithmParameters::EllipticCurve(params) => matches!(
+            (&params.curve, alg),
+            (EllipticCurve::P256, Algorithm::ES256) | (EllipticCurve::P384, Algorithm::ES384)
+        ),
+        AlgorithmParameters::OctetKeyPair(params) => {
+            params.curve == EllipticCurve::Ed25519 && alg == Algorithm::EdDSA
+        }
+        AlgorithmParameters::OctetKey(_) => false,
+    };
+    type_matches
+        && jwk
+            .common
+            .key_algorithm
+            .map_or(true, |declared| key_algorithm_name(declared) == Some(alg))
+}
+
+/// The signing algorithm a JWK `alg` names, if it is one.
+fn key_algorithm_name(alg: KeyAlgorithm) -> Option<Algorithm> {
+    Some(match alg {
+        KeyAlgorithm::HS256 => Algorithm::HS256,
+        KeyAlgorithm::HS384 => Algorithm::HS384,
+        KeyAlgorithm::HS512 => Algorithm::HS512,
+        KeyAlgorithm::ES256 => Algorithm::ES256,
+        KeyAlgorithm::ES384 => Algorithm::ES384,
+        KeyAlgorithm::RS256 => Algorithm::RS256,
+        KeyAlgorithm::RS384 => Algorithm::RS384,
+        KeyAlgorithm::RS512 => Algorithm::RS512,
+        KeyAlgorithm::PS256 => Algorithm::PS256,
+        KeyAlgorithm::PS384 => Algorithm::PS384,
+        KeyAlgorithm::PS512 => Algorithm::PS512,
+        KeyAlgorithm::EdDSA => Algorithm::EdDSA,
+        _ => return None,
+    })
+}
+
+/// Describe why a token failed validation, without echoing its contents.
+fn rejection_reason(kind: &ErrorKind) -> String {
+    match kind {
+        ErrorKind::ExpiredSignature => "Token expired".to_string(),
+        ErrorKind::ImmatureSignature => "Token not yet valid".to_string(),
+        ErrorKind::InvalidIssuer => "Invalid issuer".to_string(),
+        ErrorKind::InvalidAudience => "Invalid audience".to_string(),
+        ErrorKind::InvalidSignature => "Invalid signature".to_string(),
+        ErrorKind::InvalidAlgorithm => "Invalid algorithm".to_string(),
+        ErrorKind::MissingRequiredClaim(claim) => format!("Missing claim '{}'", claim),
+        _ => "Malformed token".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn principal(claims: serde_json::Value) -> Principal {
+        serde_json::from_value::<Claims>(claims)
+            .unwrap()
+            .into_principal()
+    }
+
+    #[test]
+    fn test_scopes_from_claims() {
+        let p = principal(serde_json::json!({ "sub": "alice", "scope": "a  b" }));
+        assert_eq!(p, Principal::new("alice").with_scopes(["a", "b"]));
+        let p = principal(serde_json::json!({ "sub": "bob", "scp": ["a", "b"] }));
+        assert_eq!(p.scopes, ["a", "b"]);
+        let p = principal(serde_json::json!({ "sub": "carol", "scp": "a b" }));
+        assert_eq!(p.scopes, ["a", "b"]);
+        assert!(principal(serde_json::json!({ "sub": "dave" }))
+            .scopes
+            .is_empty());
+    }
+
+    #[test]
+    fn test_key_accepts_matching_algorithms_only() {
+        let jwk: Jwk = serde_json::from_value(serde_json::json!({
+            "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }))
+        .unwrap();
+        assert!(key_accepts(&jwk, Algorithm::EdDSA));
+        assert!(!key_accepts(&jwk, Algorithm::HS256));
+        assert!(!key_accepts(&jwk, Algorithm::ES256));
+
+        let secret: Jwk = serde_json::from_value(serde_json::json!({
+            "kty": "oct", "k": "c2VjcmV0"
+        }))
+        .unwrap();
+        assert!(!key_accepts(&secret, Algorithm::HS256));
+    }
+}
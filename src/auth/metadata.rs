@@ -0,0 +1,128 @@
+//! OAuth 2.0 Protected Resource Metadata (RFC 9728).
+
+use axum::http::Uri;
+use serde::{Deserialize, Serialize};
+
+/// Path at which [`ProtectedResourceMetadata`] is served.
+pub const PROTECTED_RESOURCE_METADATA_PATH: &str = "/.well-known/oauth-protected-resource";
+
+/// Describes the server as an OAuth 2.0 protected resource (RFC 9728).
+///
+/// MCP clients read this document to discover which authorization servers
+/// issue tokens for the server. When an [`Authenticator`](super::Authenticator)
+/// returns it from
+/// [`resource_metadata`](super::Authenticator::resource_metadata), the router
+/// serves it, unauthenticated, at [`PROTECTED_RESOURCE_METADATA_PATH`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtectedResourceMetadata {
+    /// The resource identifier: the canonical URL of the MCP server.
+    pub resource: String,
+
+    /// Issuer URLs of the authorization servers that issue tokens for it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authorization_servers: Vec<String>,
+
+    /// Scopes used in authorization requests for the resource.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes_supported: Vec<String>,
+
+    /// How tokens may be presented; only the `Authorization` header is supported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bearer_methods_supported: Vec<String>,
+
+    /// Human-readable name of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_name: Option<String>,
+
+    /// URL of documentation for developers using the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_documentation: Option<String>,
+}
+
+impl ProtectedResourceMetadata {
+    /// Create metadata for the resource at `resource`.
+    pub fn new(resource: impl Into<String>) -> Self {
+        Self {
+            resource: resource.into(),
+            authorization_servers: Vec::new(),
+            scopes_supported: Vec::new(),
+            bearer_methods_supported: vec!["header".to_string()],
+            resource_name: None,
+            resource_documentation: None,
+        }
+    }
+
+    /// Add an authorization server by its issuer URL (builder style).
+    pub fn with_authorization_server(mut self, issuer: impl Into<String>) -> Self {
+        self.authorization_servers.push(issuer.into());
+        self
+    }
+
+    /// Set the supported scopes (builder style).
+    pub fn with_scopes_supported<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes_supported = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the human-readable resource name (builder style).
+    pub fn with_resource_name(mut self, name: impl Into<String>) -> Self {
+        self.resource_name = Some(name.into());
+        self
+    }
+
+    /// Set the documentation URL (builder style).
+    pub fn with_resource_documentation(mut self, url: impl Into<String>) -> Self {
+        self.resource_documentation = Some(url.into());
+        self
+    }
+
+    /// Absolute URL of this document, for the `resource_metadata` parameter
+    /// of `WWW-Authenticate` challenges.
+    ///
+    /// Returns `None` if [`resource`](Self::resource) is not an absolute URL.
+    pub fn metadata_url(&self) -> Option<String> {
+        let uri: Uri = self.resource.parse().ok()?;
+        Some(format!(
+            "{}://{}{}",
+            uri.scheme_str()?,
+            uri.authority()?,
+            PROTECTED_RESOURCE_METADATA_PATH
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_url() {
+        let metadata = ProtectedResourceMetadata::new("https://mcp.example.com:8443/mcp");
+        assert_eq!(
+            metadata.metadata_url().as_deref(),
+            Some("https://mcp.example.com:8443/.well-known/oauth-protected-resource")
+        );
+        assert_eq!(
+            ProtectedResourceMetadata::new("mcp-server").metadata_url(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_serialization_skips_empty_fields() {
+        let metadata = ProtectedResourceMetadata::new("https://mcp.example.com/mcp")
+            .with_authorization_server("https://auth.example.com");
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({
+                "resource": "https://mcp.example.com/mcp",
+                "authorization_servers": ["https://auth.example.com"],
+                "bearer_methods_supported": ["header"]
+            })
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Authentication of HTTP requests.
+//!
+//! An [`Authenticator`] turns the credentials of a request into a
+//! [`Principal`]. Install one with
+//! [`McpServer::with_authenticator`](crate::McpServer::with_authenticator) and
+//! every route except `/health` requires credentials: requests that fail get
+//! `401 Unauthorized` with a `WWW-Authenticate` challenge (RFC 6750), and the
+//! principal of those that pass is available to handlers through
+//! [`RequestContext::principal`](crate::RequestContext::principal).
+//!
+//! [`ApiKeyAuthenticator`] checks static API keys. With the `jwt` feature,
+//! `JwtAuthenticator` makes the server an OAuth 2.1 resource server that
+//! validates bearer JWTs against a JWKS and publishes
+//! [`ProtectedResourceMetadata`]. Implement the trait to plug in another
+//! scheme.
+//!
+//! Authentication lets every caller use every handler. To restrict individual
+//! tools, resources, and prompts by scope, role, or any other property of the
+//! request, attach an [`AccessPolicy`].
+//!
+//! The stdio transport has no HTTP request and is not authenticated: a stdio
+//! server is only reachable by the process that spawned it.
+
+mod api_key;
+#[cfg(feature = "jwt")]
+mod jwt;
+mod metadata;
+mod policy;
+
+pub use api_key::ApiKeyAuthenticator;
+#[cfg(feature = "jwt")]
+pub use jwt::JwtAuthenticator;
+pub use metadata::{ProtectedResourceMetadata, PROTECTED_RESOURCE_METADATA_PATH};
+pub use policy::AccessPolicy;
+
+use crate::context::Principal;
+use crate::error::HttpError;
+use async_trait::async_trait;
+use axum::{
+    extract::{Request, State},
+    http::{header, request::Parts, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Realm named in `WWW-Authenticate` challenges unless configured otherwise.
+pub const DEFAULT_REALM: &str = "mcp";
+
+/// Why a request could not be authenticated.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AuthError {
+    /// The request carries no credentials.
+    #[error("Missing credentials")]
+    MissingCredentials,
+
+    /// The request's credentials were rejected.
+    #[error("Invalid credentials: {0}")]
+    InvalidCredentials(String),
+
+    /// Credentials could not be checked, e.g. because signing keys could not
+    /// be fetched. Answered with `503 Service Unavailable`.
+    #[error("Authentication unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl AuthError {
+    /// Create an invalid-credentials error.
+    pub fn invalid_credentials(reason: impl Into<String>) -> Self {
+        Self::InvalidCredentials(reason.into())
+    }
+}
+
+/// Verifies the credentials of HTTP requests.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::http::request::Parts;
+/// use axum_mcp::auth::{bearer_token, AuthError, Authenticator};
+/// use axum_mcp::Principal;
+/// use async_trait::async_trait;
+///
+/// struct SessionCookieAuth;
+///
+/// #[async_trait]
+/// impl Authenticator for SessionCookieAuth {
+///     async fn authenticate(&self, request: &Parts) -> Result<Principal, AuthError> {
+///         let token = bearer_token(&request.headers).ok_or(AuthError::MissingCredentials)?;
+///         // ... look the token up ...
+///         # let _ = token;
+///         Ok(Principal::new("alice"))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Authenticator: Send + Sync {
+    /// Authenticate a request from its head (method, URI, headers, extensions).
+    async fn authenticate(&self, request: &Parts) -> Result<Principal, AuthError>;
+
+    /// The `WWW-Authenticate` header value sent when authentication fails.
+    ///
+    /// Defaults to a `Bearer` challenge for [`DEFAULT_REALM`]; see
+    /// [`bearer_challenge`].
+    fn challenge(&self, error: &AuthError) -> String {
+        bearer_challenge(DEFAULT_REALM, error)
+    }
+
+    /// Metadata describing the server as an OAuth protected resource.
+    ///
+    /// If this returns `Some`, the router serves it at
+    /// [`PROTECTED_RESOURCE_METADATA_PATH`] without authentication. Defaults
+    /// to `None`.
+    fn resource_metadata(&self) -> Option<ProtectedResourceMetadata> {
+        None
+    }
+}
+
+/// Build an RFC 6750 `Bearer` challenge.
+///
+/// Requests without credentials get a bare challenge naming the realm;
+/// rejected credentials add `error="invalid_token"` and a description.
+pub fn bearer_challenge(realm: &str, error: &AuthError) -> String {
+    match error {
+        AuthError::MissingCredentials | AuthError::Unavailable(_) => {
+            format!("Bearer realm=\"{}\"", quote_safe(realm))
+        }
+        AuthError::InvalidCredentials(reason) => format!(
+            "Bearer realm=\"{}\", error=\"invalid_token\", error_description=\"{}\"",
+            quote_safe(realm),
+            quote_safe(reason)
+        ),
+    }
+}
+
+/// Extract the token of an `Authorization: Bearer <token>` header.
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// Keep a challenge parameter inside its quotes.
+pub(crate) fn quote_safe(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| *c != '"' && *c != '\\' && !c.is_control())
+        .collect()
+}
+
+/// Middleware that authenticates each request and records its principal.
+pub(crate) async fn require_auth(
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (mut parts, body) = request.into_parts();
+    match authenticator.authenticate(&parts).await {
+        Ok(principal) => {
+            tracing::debug!(principal = %principal.id, "request authenticated");
+            parts.extensions.insert(principal);
+            next.run(Request::from_parts(parts, body)).await
+        }
+        Err(AuthError::Unavailable(reason)) => {
+            tracing::warn!(uri = %parts.uri, "authentication unavailable: {}", reason);
+            HttpError::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Authentication temporarily unavailable".to_string(),
+            )
+            .into_response()
+        }
+        Err(error) => {
+            tracing::info!(uri = %parts.uri, "authentication failed: {}", error);
+            let mut response = HttpError::unauthorized(error.to_string()).into_response();
+            if let Ok(value) = HeaderValue::from_str(&authenticator.challenge(&error)) {
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, value);
+            }
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_challenge() {
+        assert_eq!(
+            bearer_challenge("mcp", &AuthError::MissingCredentials),
+            "Bearer realm=\"mcp\""
+        );
+        assert_eq!(
+            bearer_challenge("mcp", &AuthError::invalid_credentials("Bad \"key\"")),
+            "Bearer realm=\"mcp\", error=\"invalid_token\", error_description=\"Bad key\""
+        );
+    }
+
+    #[test]
+    fn test_bearer_token() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(bearer_token(&headers), None);
+        headers.insert(header::AUTHORIZATION, "bearer abc".parse().unwrap());
+        assert_eq!(bearer_token(&headers), Some("abc"));
+        headers.insert(header::AUTHORIZATION, "Basic abc".parse().unwrap());
+        assert_eq!(bearer_token(&headers), None);
+        headers.insert(header::AUTHORIZATION, "Bearer ".parse().unwrap());
+        assert_eq!(bearer_token(&headers), None);
+    }
+}
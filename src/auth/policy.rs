@@ -0,0 +1,113 @@
+//! Per-handler authorization.
+
+use crate::context::RequestContext;
+use std::fmt;
+use std::sync::Arc;
+
+/// Decides which callers may see and use a tool, resource, or prompt.
+///
+/// Attach policies with
+/// [`McpServer::tool_with_policy`](crate::McpServer::tool_with_policy),
+/// [`McpServer::prompt_with_policy`](crate::McpServer::prompt_with_policy), and
+/// [`McpServer::with_resource_policy`](crate::McpServer::with_resource_policy).
+/// Handlers a caller is not allowed to use are left out of its `*/list`
+/// results, and using them anyway fails with `403 Forbidden` (JSON-RPC error
+/// [`FORBIDDEN`](crate::jsonrpc::FORBIDDEN)).
+///
+/// The scope-based policies check the [`Principal`](crate::Principal) set by
+/// authentication, so they deny every caller on a server without an
+/// [`Authenticator`](super::Authenticator) and on stdio.
+///
+/// # Example
+///
+/// ```rust
+/// use axum_mcp::auth::AccessPolicy;
+///
+/// let admins = AccessPolicy::require_scopes(["admin"]);
+/// let editors = AccessPolicy::any_scope(["admin", "editor"]);
+/// let internal = AccessPolicy::new(|ctx| {
+///     ctx.principal().is_some_and(|p| p.id.ends_with("@example.com"))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct AccessPolicy {
+    rule: Rule,
+}
+
+#[derive(Clone)]
+enum Rule {
+    Authenticated,
+    AllScopes(Vec<String>),
+    AnyScope(Vec<String>),
+    Custom(Arc<dyn Fn(&RequestContext) -> bool + Send + Sync>),
+}
+
+impl AccessPolicy {
+    /// Allow the requests for which `check` returns `true`.
+    pub fn new<F>(check: F) -> Self
+    where
+        F: Fn(&RequestContext) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            rule: Rule::Custom(Arc::new(check)),
+        }
+    }
+
+    /// Allow any authenticated caller.
+    pub fn authenticated() -> Self {
+        Self {
+            rule: Rule::Authenticated,
+        }
+    }
+
+    /// Allow callers holding every one of `scopes`.
+    pub fn require_scopes<I, S>(scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            rule: Rule::AllScopes(scopes.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Allow callers holding at least one of `scopes`, e.g. one of several roles.
+    pub fn any_scope<I, S>(scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            rule: Rule::AnyScope(scopes.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Returns `true` if the policy allows the request described by `ctx`.
+    pub fn allows(&self, ctx: &RequestContext) -> bool {
+        match &self.rule {
+            Rule::Custom(check) => check(ctx),
+            rule => ctx.principal().is_some_and(|principal| match rule {
+                Rule::AllScopes(scopes) => scopes.iter().all(|s| principal.has_scope(s)),
+                Rule::AnyScope(scopes) => scopes.iter().any(|s| principal.has_scope(s)),
+                _ => true,
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for AccessPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rule {
+            Rule::Authenticated => f.write_str("AccessPolicy::Authenticated"),
+            Rule::AllScopes(scopes) => f
+                .debug_tuple("AccessPolicy::AllScopes")
+                .field(scopes)
+                .finish(),
+            Rule::AnyScope(scopes) => f
+                .debug_tuple("AccessPolicy::AnyScope")
+                .field(scopes)
+                .finish(),
+            Rule::Custom(_) => f.write_str("AccessPolicy::Custom"),
+        }
+    }
+}
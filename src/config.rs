@@ -1,5 +1,6 @@
 //! Configuration options for MCP server.
 
+use crate::tool::ToolFilter;
 use std::time::Duration;
 
 /// Configuration for MCP server behavior.
@@ -13,6 +14,21 @@ pub struct ServerConfig {
     pub prompt_timeout: Duration,
     /// Maximum request body size in bytes (default: 10MB).
     pub max_body_size: usize,
+    /// Path of the JSON-RPC endpoint (default: `/mcp`).
+    pub mcp_path: String,
+    /// Idle time after which a session expires (default: 30 minutes).
+    pub session_timeout: Duration,
+    /// Maximum number of items per page of a list result (default: 100).
+    pub page_size: usize,
+    /// Whether tool results are validated against the tool's output schema
+    /// (default: on in debug builds, off in release builds).
+    pub validate_output: bool,
+    /// Whether the REST `/tools/call` route reports tool failures as `isError`
+    /// results (default: `false`). JSON-RPC always does, as the spec requires.
+    pub tool_errors_as_results: bool,
+    /// Which tools the server lets be called, judged by their annotations
+    /// (default: all of them).
+    pub tool_filter: Option<ToolFilter>,
 }
 
 impl Default for ServerConfig {
@@ -22,6 +38,12 @@ impl Default for ServerConfig {
             resource_timeout: Duration::from_secs(30),
             prompt_timeout: Duration::from_secs(30),
             max_body_size: 10 * 1024 * 1024, // 10MB
+            mcp_path: "/mcp".to_string(),
+            session_timeout: Duration::from_secs(30 * 60),
+            page_size: 100,
+            validate_output: cfg!(debug_assertions),
+            tool_errors_as_results: false,
+            tool_filter: None,
         }
     }
 }
@@ -55,4 +77,76 @@ impl ServerConfig {
         self.max_body_size = size;
         self
     }
+
+    /// Set the path of the JSON-RPC endpoint.
+    pub fn with_mcp_path(mut self, path: impl Into<String>) -> Self {
+        self.mcp_path = path.into();
+        self
+    }
+
+    /// Set the idle timeout for sessions.
+    pub fn with_session_timeout(mut self, timeout: Duration) -> Self {
+        self.session_timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of items per page of `tools/list`,
+    /// `resources/list`, `resources/templates/list`, and `prompts/list`.
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn with_page_size(mut self, size: usize) -> Self {
+        self.page_size = size;
+        self
+    }
+
+    /// Enable or disable validation of tool results against their output schema.
+    ///
+    /// When enabled, a tool that declares an output schema but returns missing
+    /// or non-conforming structured content fails the call with an internal
+    /// error. Enable it in release builds for a strict server.
+    pub fn with_output_validation(mut self, enabled: bool) -> Self {
+        self.validate_output = enabled;
+        self
+    }
+
+    /// Choose how the REST `/tools/call` route reports tool failures.
+    ///
+    /// By default a tool that returns `Err` is answered with HTTP 500, as
+    /// existing REST clients expect. Pass `true` to produce a `200 OK` result
+    /// with `isError: true` and the message as text instead, as JSON-RPC does.
+    pub fn with_tool_errors_as_results(mut self, enabled: bool) -> Self {
+        self.tool_errors_as_results = enabled;
+        self
+    }
+
+    /// Refuse calls to the tools `filter` does not allow, based on their
+    /// [`annotations`](crate::Tool::annotations).
+    ///
+    /// Refused calls fail with HTTP 403 (JSON-RPC error `-32003`). Tools stay
+    /// listed. For example, [`ToolFilter::deny_destructive`] refuses tools
+    /// that may be destructive, including tools without annotations.
+    pub fn with_tool_filter(mut self, filter: ToolFilter) -> Self {
+        self.tool_filter = Some(filter);
+        self
+    }
+
+    /// Enable or disable read-only mode.
+    ///
+    /// Shorthand for [`with_tool_filter`](Self::with_tool_filter) with
+    /// [`ToolFilter::read_only`]: calling a tool that lacks
+    /// `readOnlyHint: true` is refused. This covers every destructive tool, and
+    /// tools without annotations. Disabling it removes the read-only filter,
+    /// but leaves any other tool filter in place.
+    pub fn with_read_only(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.tool_filter = Some(ToolFilter::read_only());
+        } else if self
+            .tool_filter
+            .as_ref()
+            .is_some_and(ToolFilter::is_read_only)
+        {
+            self.tool_filter = None;
+        }
+        self
+    }
 }
@@ -0,0 +1,364 @@
+//! Content returned by tool calls.
+//!
+//! A tool result is a list of [`Content`] blocks: text, images, audio,
+//! embedded resources, and links to resources the client can read later.
+//! Tools that return a plain JSON [`Value`] get a single text block holding the
+//! serialized value, via `From<Value> for ToolResult`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use axum_mcp::{Content, RequestContext, ResourceLink, Tool, ToolError, ToolResult};
+//! use async_trait::async_trait;
+//! use serde_json::Value;
+//!
+//! struct ChartTool;
+//!
+//! #[async_trait]
+//! impl Tool for ChartTool {
+//!     fn description(&self) -> &str { "Render a chart" }
+//!     fn schema(&self) -> Value { serde_json::json!({ "type": "object" }) }
+//!
+//!     async fn invoke(&self, _: &Value, _: &RequestContext) -> Result<ToolResult, ToolError> {
+//!         let png: Vec<u8> = render_chart();
+//!         Ok(ToolResult::new(vec![
+//!             Content::text("Revenue by quarter"),
+//!             Content::image(png, "image/png"),
+//!             ResourceLink::new("file:///data/revenue.csv", "revenue.csv")
+//!                 .with_mime_type("text/csv")
+//!                 .into(),
+//!         ]))
+//!     }
+//! }
+//! # fn render_chart() -> Vec<u8> { Vec::new() }
+//! ```
+
+use crate::resource::ResourceContents;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The result of a tool call.
+///
+/// Serializes to the MCP `CallToolResult` shape.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolResult {
+    /// Content blocks, in order.
+    pub content: Vec<Content>,
+    /// Machine-readable result, conforming to the tool's output schema.
+    #[serde(
+        rename = "structuredContent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub structured_content: Option<Value>,
+    /// Whether the tool failed. The content then describes the error, so the
+    /// model can see it and react.
+    #[serde(
+        rename = "isError",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub is_error: bool,
+}
+
+impl ToolResult {
+    /// Create a result from content blocks.
+    pub fn new(content: Vec<Content>) -> Self {
+        Self {
+            content,
+            structured_content: None,
+            is_error: false,
+        }
+    }
+
+    /// Create a result holding a single text block.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(vec![Content::text(text)])
+    }
+
+    /// Create an error result with `message` as its text.
+    ///
+    /// This is what the server sends when a tool returns `Err`.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            is_error: true,
+            ..Self::text(message)
+        }
+    }
+
+    /// Create a structured result, with its serialization as the text fallback.
+    ///
+    /// This is what tools with an [output schema](crate::Tool::output_schema)
+    /// return by default.
+    pub fn structured(value: Value) -> Self {
+        Self::text(value.to_string()).with_structured_content(value)
+    }
+
+    /// Set the structured content (builder style).
+    pub fn with_structured_content(mut self, value: Value) -> Self {
+        self.structured_content = Some(value);
+        self
+    }
+
+    /// Append a content block (builder style).
+    pub fn with_content(mut self, content: impl Into<Content>) -> Self {
+        self.content.push(content.into());
+        self
+    }
+
+    /// Convert the result back into the value a plain tool would return.
+    ///
+    /// Error results become their text, structured content is returned as is,
+    /// and a single text block is parsed as JSON (or kept as a string). Other
+    /// content is returned as its serialized blocks.
+    pub(crate) fn into_value(self) -> Result<Value, String> {
+        if self.is_error {
+            return Err(self.text_content());
+        }
+        if let Some(value) = self.structured_content {
+            return Ok(value);
+        }
+        match self.content.as_slice() {
+            [Content::Text { text, .. }] => {
+                Ok(serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.clone())))
+            }
+            content => Ok(serde_json::to_value(content).unwrap_or_default()),
+        }
+    }
+
+    /// The text blocks of the result, joined by newlines.
+    pub fn text_content(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                Content::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl From<Value> for ToolResult {
+    /// Wrap a JSON value in a single text block holding its serialization.
+    fn from(value: Value) -> Self {
+        Self::text(value.to_string())
+    }
+}
+
+impl From<Content> for ToolResult {
+    fn from(content: Content) -> Self {
+        Self::new(vec![content])
+    }
+}
+
+/// One block of tool result content.
+///
+/// Serializes with a `type` tag of `text`, `image`, `audio`, `resource`, or
+/// `resource_link`.
+///
+/// # Example
+///
+/// ```
+/// use axum_mcp::{Annotations, Content, Role};
+///
+/// let content = Content::audio([0x52, 0x49, 0x46, 0x46], "audio/wav")
+///     .with_annotations(Annotations::new().with_audience([Role::User]));
+/// let json = serde_json::to_value(&content).unwrap();
+/// assert_eq!(json["type"], "audio");
+/// assert_eq!(json["data"], "UklGRg==");
+/// assert_eq!(json["annotations"]["audience"][0], "user");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Content {
+    /// Plain text.
+    Text {
+        /// The text itself.
+        text: String,
+        /// Optional hints for the client.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// An image, base64-encoded.
+    Image {
+        /// Base64-encoded image data.
+        data: String,
+        /// MIME type of the image, e.g. `image/png`.
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Optional hints for the client.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// Audio, base64-encoded.
+    Audio {
+        /// Base64-encoded audio data.
+        data: String,
+        /// MIME type of the audio, e.g. `audio/wav`.
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Optional hints for the client.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// The contents of a resource, embedded in the result.
+    Resource {
+        /// The embedded contents.
+        resource: ResourceContents,
+        /// Optional hints for the client.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// A link to a resource the client may read with `resources/read`.
+    ResourceLink(ResourceLink),
+}
+
+impl Content {
+    /// Create a text block.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create an image block, base64-encoding `data`.
+    pub fn image(data: impl AsRef<[u8]>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: base64::engine::general_purpose::STANDARD.encode(data),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create an audio block, base64-encoding `data`.
+    pub fn audio(data: impl AsRef<[u8]>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: base64::engine::general_purpose::STANDARD.encode(data),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Embed resource contents.
+    pub fn resource(resource: ResourceContents) -> Self {
+        Self::Resource {
+            resource,
+            annotations: None,
+        }
+    }
+
+    /// Set the block's annotations (builder style).
+    pub fn with_annotations(mut self, annotations: Annotations) -> Self {
+        match &mut self {
+            Self::Text { annotations: a, .. }
+            | Self::Image { annotations: a, .. }
+            | Self::Audio { annotations: a, .. }
+            | Self::Resource { annotations: a, .. } => *a = Some(annotations),
+            Self::ResourceLink(link) => link.annotations = Some(annotations),
+        }
+        self
+    }
+}
+
+impl From<ResourceLink> for Content {
+    fn from(link: ResourceLink) -> Self {
+        Self::ResourceLink(link)
+    }
+}
+
+/// A link to a resource, returned in place of its contents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLink {
+    /// URI of the linked resource.
+    pub uri: String,
+    /// Display name of the resource.
+    pub name: String,
+    /// Description of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// MIME type of the resource, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Optional hints for the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Annotations>,
+}
+
+impl ResourceLink {
+    /// Create a link to the resource at `uri`.
+    pub fn new(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            name: name.into(),
+            description: None,
+            mime_type: None,
+            annotations: None,
+        }
+    }
+
+    /// Set the description (builder style).
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the MIME type (builder style).
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+}
+
+/// Hints about how a client should use a content block.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotations {
+    /// Who the content is intended for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audience: Option<Vec<Role>>,
+    /// Importance from 0.0 (least) to 1.0 (most).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// When the content was last modified, as an ISO 8601 timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl Annotations {
+    /// Create empty annotations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the intended audience (builder style).
+    pub fn with_audience(mut self, audience: impl IntoIterator<Item = Role>) -> Self {
+        self.audience = Some(audience.into_iter().collect());
+        self
+    }
+
+    /// Set the priority, clamped to `0.0..=1.0` (builder style).
+    pub fn with_priority(mut self, priority: f64) -> Self {
+        self.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the last-modified timestamp (builder style).
+    pub fn with_last_modified(mut self, timestamp: impl Into<String>) -> Self {
+        self.last_modified = Some(timestamp.into());
+        self
+    }
+}
+
+/// A participant in the conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// The human user.
+    User,
+    /// The model.
+    Assistant,
+}
@@ -0,0 +1,394 @@
+//! Per-request context passed to handlers.
+//!
+//! A [`RequestContext`] lets a tool talk back to the client while it runs, e.g.
+//! to report progress on long operations. When a Streamable HTTP request is
+//! answered with plain JSON, progress goes to the session's `GET` event stream
+//! instead, if the client has one open. Where nothing can carry messages (the
+//! REST routes, or plain JSON without a `GET` stream) reporting is a no-op, so
+//! handlers never need to check.
+//!
+//! Handlers can send log messages the same way with [`RequestContext::log`];
+//! messages below the level the client chose with `logging/setLevel` are
+//! dropped.
+//!
+//! The context also carries a [`CancellationToken`] that fires when the client
+//! cancels the request, disconnects, or the tool times out.
+//!
+//! Handlers can also see who is asking: the HTTP request's headers and
+//! extensions (including the `x-request-id` and an authenticated
+//! [`Principal`]), the MCP session, and the deadline after which the server
+//! abandons the call. Over stdio there is no HTTP request, so headers and
+//! extensions are empty.
+//!
+//! Finally, the context gives access to state shared by the whole server, such
+//! as a database pool (see [`RequestContext::state`]).
+
+use crate::jsonrpc::{RequestId, JSONRPC_VERSION};
+use crate::lifecycle::LoggingLevel;
+use crate::session::Session;
+use axum::http::{Extensions, HeaderMap};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Token a client attaches to a request (`_meta.progressToken`) to receive
+/// progress notifications for it. Like a request id, it is a string or integer.
+pub type ProgressToken = RequestId;
+
+/// Context for a single client request.
+///
+/// Clones refer to the same request: they share its cancellation token and
+/// progress reporting, so a clone can be moved into a spawned task or future.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_mcp::{RequestContext, Tool};
+/// use async_trait::async_trait;
+/// use serde_json::{json, Value};
+///
+/// struct IndexTool;
+///
+/// #[async_trait]
+/// impl Tool for IndexTool {
+///     fn description(&self) -> &str { "Index all documents" }
+///     fn schema(&self) -> Value { json!({ "type": "object" }) }
+///
+///     async fn call_with_context(
+///         &self,
+///         _arguments: &Value,
+///         ctx: &RequestContext,
+///     ) -> Result<Value, String> {
+///         for i in 0..10 {
+///             // ... index a batch ...
+///             ctx.report_progress(i as f64 + 1.0, Some(10.0), Some("Indexing"));
+///         }
+///         Ok(json!({ "indexed": 10 }))
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    progress_token: Option<ProgressToken>,
+    outlet: Option<Outlet>,
+    last_progress: Arc<Mutex<Option<f64>>>,
+    cancellation: CancellationToken,
+    http: HttpParts,
+    session: Option<Arc<Session>>,
+    deadline: OnceLock<Instant>,
+    state: Arc<Extensions>,
+}
+
+impl RequestContext {
+    /// Create a context that is not attached to any client.
+    ///
+    /// Useful in tests and when calling handlers directly; reporting is a no-op.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn with_progress_token(mut self, token: Option<ProgressToken>) -> Self {
+        self.progress_token = token;
+        self
+    }
+
+    pub(crate) fn with_outlet(mut self, outlet: Option<Outlet>) -> Self {
+        self.outlet = outlet;
+        self
+    }
+
+    pub(crate) fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    pub(crate) fn with_http(mut self, http: HttpParts) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub(crate) fn with_session(mut self, session: Arc<Session>) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    pub(crate) fn with_state(mut self, state: Arc<Extensions>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Start the clock on the handler: the deadline is `timeout` from now.
+    ///
+    /// Only the first call has an effect; the deadline is returned either way.
+    pub(crate) fn start_deadline(&self, timeout: Duration) -> Instant {
+        *self.deadline.get_or_init(|| Instant::now() + timeout)
+    }
+
+    /// The `x-request-id` of the HTTP request, if any.
+    ///
+    /// The router assigns one to every request that lacks it, so this is
+    /// `None` only outside HTTP (stdio, or a context built with [`new`](Self::new)).
+    pub fn request_id(&self) -> Option<&str> {
+        self.http
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    /// The authenticated caller, if authentication middleware inserted a
+    /// [`Principal`] into the request's extensions.
+    pub fn principal(&self) -> Option<&Principal> {
+        self.http.extensions.get::<Principal>()
+    }
+
+    /// Headers of the HTTP request.
+    ///
+    /// For WebSocket connections these are the headers of the upgrade request.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.http.headers
+    }
+
+    /// Extensions of the HTTP request, as set by middleware.
+    ///
+    /// For WebSocket connections these are the extensions of the upgrade request.
+    pub fn extensions(&self) -> &Extensions {
+        &self.http.extensions
+    }
+
+    /// The MCP session the request belongs to.
+    ///
+    /// `None` for the REST routes, which are sessionless.
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_deref()
+    }
+
+    /// Identifier of the MCP session, if any.
+    pub fn session_id(&self) -> Option<&str> {
+        self.session().map(Session::id)
+    }
+
+    /// Shared state of type `S`, as set with
+    /// [`McpServer::with_state`](crate::McpServer::with_state).
+    ///
+    /// Returns `None` if the server has no state of that type.
+    pub fn state<S>(&self) -> Option<&S>
+    where
+        S: Send + Sync + 'static,
+    {
+        self.state.get::<S>()
+    }
+
+    /// When the server will give up on the handler, per the configured timeout.
+    ///
+    /// `None` until the handler has been started by the server.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline.get().copied()
+    }
+
+    /// Time left until the [`deadline`](Self::deadline), zero once it passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline()
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Token that is cancelled when the client gives up on this request.
+    ///
+    /// The server stops polling the call as soon as the request is cancelled,
+    /// so code after an `.await` may never run. Work that must complete, such
+    /// as rolling back a transaction, should watch this token from a spawned
+    /// task or a `Drop` guard.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Returns `true` once the request has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// The progress token supplied by the client, if any.
+    pub fn progress_token(&self) -> Option<&ProgressToken> {
+        self.progress_token.as_ref()
+    }
+
+    /// Report progress on the current request.
+    ///
+    /// Sends `notifications/progress` if the client asked for progress (by
+    /// sending a `progressToken`) and the transport can stream messages;
+    /// otherwise does nothing. Requests answered with plain JSON fall back to
+    /// the session's `GET` event stream. `progress` must increase with every
+    /// call, so values that do not are dropped. Returns `true` if a
+    /// notification was sent.
+    pub fn report_progress(
+        &self,
+        progress: f64,
+        total: Option<f64>,
+        message: Option<&str>,
+    ) -> bool {
+        let Some(token) = &self.progress_token else {
+            return false;
+        };
+        if self.outlet.is_none() && self.session.is_none() {
+            return false;
+        }
+
+        {
+            let mut last = self.last_progress.lock().unwrap_or_else(|e| e.into_inner());
+            if last.is_some_and(|last| progress <= last) {
+                tracing::debug!(progress, "Dropping non-increasing progress update");
+                return false;
+            }
+            *last = Some(progress);
+        }
+
+        let mut params = json!({ "progressToken": token, "progress": progress });
+        if let Some(total) = total {
+            params["total"] = json!(total);
+        }
+        if let Some(message) = message {
+            params["message"] = json!(message);
+        }
+        self.send_notification("notifications/progress", params)
+    }
+
+    /// Send a log message to the client.
+    ///
+    /// Sends `notifications/message` unless `level` is below the level the
+    /// client set with `logging/setLevel`. Like progress, messages go out with
+    /// the response stream or, failing that, the session's `GET` event stream.
+    /// Returns `true` if a notification was sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum_mcp::{LoggingLevel, RequestContext};
+    /// use serde_json::json;
+    ///
+    /// // A detached context has nowhere to send messages.
+    /// let ctx = RequestContext::new();
+    /// assert!(!ctx.log(LoggingLevel::Info, Some("db"), json!({ "rows": 3 })));
+    /// ```
+    pub fn log(&self, level: LoggingLevel, logger: Option<&str>, data: Value) -> bool {
+        let threshold = self.session.as_ref().and_then(|s| s.log_level());
+        if threshold.is_some_and(|threshold| level < threshold) {
+            return false;
+        }
+        let mut params = json!({ "level": level, "data": data });
+        if let Some(logger) = logger {
+            params["logger"] = json!(logger);
+        }
+        self.send_notification("notifications/message", params)
+    }
+
+    fn send_notification(&self, method: &str, params: Value) -> bool {
+        let notification = json!({
+            "jsonrpc": JSONRPC_VERSION,
+            "method": method,
+            "params": params,
+        });
+        match (&self.outlet, &self.session) {
+            (Some(outlet), _) => outlet.send(notification),
+            (None, Some(session)) => session.send(notification),
+            (None, None) => false,
+        }
+    }
+}
+
+/// The authenticated caller of a request.
+///
+/// Authentication middleware inserts a `Principal` into the request's
+/// extensions, and handlers read it back with [`RequestContext::principal`]:
+///
+/// ```rust
+/// use axum::{extract::Request, middleware::Next, response::Response};
+/// use axum_mcp::Principal;
+///
+/// async fn authenticate(mut request: Request, next: Next) -> Response {
+///     // ... verify credentials ...
+///     request
+///         .extensions_mut()
+///         .insert(Principal::new("alice").with_scopes(["tools:read"]));
+///     next.run(request).await
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// Identifier of the caller, such as a user id or the name of an API key.
+    pub id: String,
+    /// Scopes or roles granted to the caller.
+    pub scopes: Vec<String>,
+}
+
+impl Principal {
+    /// Create a principal without scopes.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Set the scopes granted to the caller.
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns `true` if the caller was granted `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
+
+impl From<&str> for Principal {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for Principal {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+/// Header carrying the request id assigned by the router.
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The parts of an HTTP request that handlers may look at.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HttpParts {
+    pub(crate) headers: HeaderMap,
+    pub(crate) extensions: Extensions,
+}
+
+impl HttpParts {
+    pub(crate) fn new(headers: HeaderMap, extensions: Extensions) -> Self {
+        Self {
+            headers,
+            extensions,
+        }
+    }
+}
+
+/// Delivers messages related to a request, in order with its response.
+#[derive(Debug, Clone)]
+pub(crate) struct Outlet(mpsc::UnboundedSender<Value>);
+
+impl Outlet {
+    pub(crate) fn new(tx: mpsc::UnboundedSender<Value>) -> Self {
+        Self(tx)
+    }
+
+    fn send(&self, message: Value) -> bool {
+        self.0.send(message).is_ok()
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for MCP server.
 
+use crate::tool_error::{ToolError, ToolErrorResponse};
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -36,6 +37,22 @@ pub enum McpError {
     Validation(String),
 }
 
+/// Errors from a server-initiated request sent with [`Session::request`](crate::Session::request).
+#[derive(Debug, Error)]
+pub enum ClientRequestError {
+    /// No transport is connected to deliver the request.
+    #[error("No transport is connected to the session")]
+    NotConnected,
+
+    /// The session ended before the client responded.
+    #[error("Session closed before the client responded")]
+    Closed,
+
+    /// The client answered with a JSON-RPC error.
+    #[error("Client returned error {}: {}", .0.code, .0.message)]
+    Rejected(crate::jsonrpc::JsonRpcError),
+}
+
 /// Structured error response for HTTP endpoints.
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
@@ -43,6 +60,9 @@ pub struct ErrorResponse {
     pub code: u16,
     /// Error message.
     pub message: String,
+    /// Machine-readable error code, present for tool errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) error_type: Option<String>,
     /// Optional error details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
@@ -54,6 +74,7 @@ impl ErrorResponse {
         Self {
             code,
             message,
+            error_type: None,
             details: None,
         }
     }
@@ -63,9 +84,15 @@ impl ErrorResponse {
         Self {
             code,
             message,
+            error_type: None,
             details: Some(details),
         }
     }
+
+    /// Machine-readable error code, present for tool errors.
+    pub fn error_type(&self) -> Option<&str> {
+        self.error_type.as_deref()
+    }
 }
 
 /// HTTP endpoint error for handler responses.
@@ -77,6 +104,8 @@ pub struct HttpError {
     pub message: String,
     /// Optional details.
     pub details: Option<String>,
+    /// Machine-readable error code, see [`ToolError::error_type`].
+    pub(crate) error_type: Option<String>,
 }
 
 impl HttpError {
@@ -86,6 +115,7 @@ impl HttpError {
             status,
             message,
             details: None,
+            error_type: None,
         }
     }
 
@@ -95,9 +125,21 @@ impl HttpError {
             status,
             message,
             details: Some(details),
+            error_type: None,
         }
     }
 
+    /// Attach a machine-readable error code (builder style).
+    pub fn with_error_type(mut self, error_type: impl Into<String>) -> Self {
+        self.error_type = Some(error_type.into());
+        self
+    }
+
+    /// Machine-readable error code, see [`ToolError::error_type`].
+    pub fn error_type(&self) -> Option<&str> {
+        self.error_type.as_deref()
+    }
+
     /// Bad request error.
     pub fn bad_request(message: String) -> Self {
         Self::new(StatusCode::BAD_REQUEST, message)
@@ -108,6 +150,16 @@ impl HttpError {
         Self::new(StatusCode::NOT_FOUND, message)
     }
 
+    /// Create an unauthorized error (401).
+    pub fn unauthorized(message: String) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, message)
+    }
+
+    /// Create a forbidden error (403).
+    pub fn forbidden(message: String) -> Self {
+        Self::new(StatusCode::FORBIDDEN, message)
+    }
+
     /// Internal server error.
     pub fn internal(message: String) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
@@ -119,8 +171,28 @@ impl IntoResponse for HttpError {
         let body = ErrorResponse {
             code: self.status.as_u16(),
             message: self.message,
+            error_type: self.error_type,
             details: self.details,
         };
         (self.status, Json(body)).into_response()
     }
 }
+
+impl From<ToolErrorResponse> for HttpError {
+    fn from(response: ToolErrorResponse) -> Self {
+        Self {
+            status: StatusCode::from_u16(response.code)
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            message: response.message,
+            details: response.details,
+            error_type: Some(response.error_type),
+        }
+    }
+}
+
+/// Maps a tool error to its status code and structured body.
+impl From<ToolError> for HttpError {
+    fn from(err: ToolError) -> Self {
+        ToolErrorResponse::from(err).into()
+    }
+}
@@ -0,0 +1,411 @@
+//! Tools, resources, and prompts backed by async closures.
+//!
+//! For handlers too small to deserve a struct and a trait impl. The
+//! [`McpServer::tool_fn`](crate::McpServer::tool_fn),
+//! [`McpServer::resource_fn`](crate::McpServer::resource_fn), and
+//! [`McpServer::prompt_fn`](crate::McpServer::prompt_fn) builders wrap a
+//! closure in [`FnTool`], [`FnResource`], or [`FnPrompt`]; the types can also
+//! be passed to any registration method, such as
+//! [`ServerHandle::add_tool`](crate::ServerHandle::add_tool).
+//!
+//! Handlers that need the [`RequestContext`], e.g. to see the caller or to
+//! report progress, are registered with
+//! [`McpServer::tool_fn_with_context`](crate::McpServer::tool_fn_with_context)
+//! and friends instead. They receive a clone of the context, so the returned
+//! future can keep using it:
+//!
+//! ```rust,no_run
+//! use axum_mcp::{McpServer, RequestContext};
+//! use serde_json::{json, Value};
+//!
+//! # fn example() -> Result<(), axum_mcp::McpError> {
+//! let server = McpServer::new().tool_fn_with_context(
+//!     "whoami",
+//!     "Describe the caller",
+//!     json!({ "type": "object" }),
+//!     |_args: Value, ctx: RequestContext| async move {
+//!         let caller = ctx.principal().map(|p| p.id.clone());
+//!         Ok(json!({ "caller": caller }))
+//!     },
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Shared state
+//!
+//! Handlers are `Fn` closures called once per request, so state such as a
+//! database pool is captured by the closure and cloned into each call's future
+//! (trait-based handlers use
+//! [`McpServer::with_state`](crate::McpServer::with_state) instead):
+//!
+//! ```rust,no_run
+//! use axum_mcp::McpServer;
+//! use serde_json::{json, Value};
+//! use std::sync::Arc;
+//!
+//! # struct Pool;
+//! # impl Pool { async fn count(&self, _: &str) -> u64 { 0 } }
+//! # fn example(pool: Arc<Pool>) -> Result<(), axum_mcp::McpError> {
+//! let server = McpServer::new().tool_fn(
+//!     "count_rows",
+//!     "Count the rows of a table",
+//!     json!({
+//!         "type": "object",
+//!         "properties": { "table": { "type": "string" } },
+//!         "required": ["table"]
+//!     }),
+//!     move |args: Value| {
+//!         let pool = Arc::clone(&pool);
+//!         async move {
+//!             let table = args["table"].as_str().unwrap_or_default();
+//!             Ok(json!({ "rows": pool.count(table).await }))
+//!         }
+//!     },
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::content::ToolResult;
+use crate::context::RequestContext;
+use crate::prompt::Prompt;
+use crate::resource::{Resource, ResourceContents};
+use crate::tool::Tool;
+use crate::tool_error::ToolError;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::fmt;
+use std::future::Future;
+
+/// A [`Tool`] that calls an async closure with the call's arguments.
+///
+/// The closure returns `Result<Value, ToolError>`; plain messages convert with
+/// `.into()` or `?` (see [`ToolError`]).
+pub struct FnTool<F> {
+    description: String,
+    schema: Value,
+    handler: F,
+}
+
+impl<F, Fut> FnTool<F>
+where
+    F: Fn(Value) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Value, ToolError>> + Send,
+{
+    /// Create a tool from its description, input schema, and handler.
+    pub fn new(description: impl Into<String>, schema: Value, handler: F) -> Self {
+        Self {
+            description: description.into(),
+            schema,
+            handler,
+        }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Tool for FnTool<F>
+where
+    F: Fn(Value) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Value, ToolError>> + Send,
+{
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn schema(&self) -> Value {
+        self.schema.clone()
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        Ok((self.handler)(arguments.clone()).await?)
+    }
+
+    async fn invoke(
+        &self,
+        arguments: &Value,
+        _ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        (self.handler)(arguments.clone())
+            .await
+            .map(ToolResult::from)
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Tool for FnTool<WithContext<F>>
+where
+    F: Fn(Value, RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Value, ToolError>> + Send,
+{
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn schema(&self) -> Value {
+        self.schema.clone()
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        self.call_with_context(arguments, &RequestContext::new())
+            .await
+    }
+
+    async fn call_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        Ok((self.handler.0)(arguments.clone(), ctx.clone()).await?)
+    }
+
+    async fn invoke(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        (self.handler.0)(arguments.clone(), ctx.clone())
+            .await
+            .map(ToolResult::from)
+    }
+}
+
+impl<F> fmt::Debug for FnTool<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnTool")
+            .field("description", &self.description)
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A closure handler that also receives the request's [`RequestContext`].
+///
+/// Built by [`FnTool::with_context`], [`FnResource::with_context`], and
+/// [`FnPrompt::with_context`].
+pub struct WithContext<F>(F);
+
+impl<F, Fut> FnTool<WithContext<F>>
+where
+    F: Fn(Value, RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Value, ToolError>> + Send,
+{
+    /// Create a tool whose handler also receives the request context.
+    pub fn with_context(description: impl Into<String>, schema: Value, handler: F) -> Self {
+        Self {
+            description: description.into(),
+            schema,
+            handler: WithContext(handler),
+        }
+    }
+}
+
+/// A [`Resource`] whose text content comes from an async closure.
+pub struct FnResource<F> {
+    name: String,
+    description: String,
+    mime_type: String,
+    handler: F,
+}
+
+impl<F, Fut> FnResource<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    /// Create a resource from its display name, description, MIME type, and handler.
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        mime_type: impl Into<String>,
+        handler: F,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            mime_type: mime_type.into(),
+            handler,
+        }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Resource for FnResource<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        (self.handler)().await
+    }
+}
+
+impl<F, Fut> FnResource<WithContext<F>>
+where
+    F: Fn(RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    /// Create a resource whose handler also receives the request context.
+    pub fn with_context(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        mime_type: impl Into<String>,
+        handler: F,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            mime_type: mime_type.into(),
+            handler: WithContext(handler),
+        }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Resource for FnResource<WithContext<F>>
+where
+    F: Fn(RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        (self.handler.0)(RequestContext::new()).await
+    }
+
+    async fn read_with_context(
+        &self,
+        uri: &str,
+        ctx: &RequestContext,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let text = (self.handler.0)(ctx.clone()).await?;
+        Ok(vec![ResourceContents::text(uri, self.mime_type(), text)])
+    }
+}
+
+impl<F> fmt::Debug for FnResource<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnResource")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("mime_type", &self.mime_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`Prompt`] rendered by an async closure from the prompt's arguments.
+pub struct FnPrompt<F> {
+    description: String,
+    arguments: Value,
+    handler: F,
+}
+
+impl<F, Fut> FnPrompt<F>
+where
+    F: Fn(Value) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    /// Create a prompt from its description, argument definitions (see
+    /// [`Prompt::arguments`]), and handler.
+    pub fn new(description: impl Into<String>, arguments: Value, handler: F) -> Self {
+        Self {
+            description: description.into(),
+            arguments,
+            handler,
+        }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Prompt for FnPrompt<F>
+where
+    F: Fn(Value) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn arguments(&self) -> Value {
+        self.arguments.clone()
+    }
+
+    async fn render(&self, arguments: &Value) -> Result<String, String> {
+        (self.handler)(arguments.clone()).await
+    }
+}
+
+impl<F, Fut> FnPrompt<WithContext<F>>
+where
+    F: Fn(Value, RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    /// Create a prompt whose handler also receives the request context.
+    pub fn with_context(description: impl Into<String>, arguments: Value, handler: F) -> Self {
+        Self {
+            description: description.into(),
+            arguments,
+            handler: WithContext(handler),
+        }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> Prompt for FnPrompt<WithContext<F>>
+where
+    F: Fn(Value, RequestContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, String>> + Send,
+{
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn arguments(&self) -> Value {
+        self.arguments.clone()
+    }
+
+    /// Renders with a detached context (see [`RequestContext::new`]).
+    async fn render(&self, arguments: &Value) -> Result<String, String> {
+        self.render_with_context(arguments, &RequestContext::new())
+            .await
+    }
+
+    async fn render_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<String, String> {
+        (self.handler.0)(arguments.clone(), ctx.clone()).await
+    }
+}
+
+impl<F> fmt::Debug for FnPrompt<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnPrompt")
+            .field("description", &self.description)
+            .field("arguments", &self.arguments)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,203 @@
+//! JSON-RPC 2.0 message types for the MCP wire protocol.
+//!
+//! MCP clients speak JSON-RPC 2.0. Every request carries an `id` and expects a
+//! response; notifications omit the `id` and never receive one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use axum_mcp::jsonrpc::{JsonRpcRequest, RequestId};
+//!
+//! let request: JsonRpcRequest = serde_json::from_value(serde_json::json!({
+//!     "jsonrpc": "2.0",
+//!     "id": 1,
+//!     "method": "tools/list"
+//! }))
+//! .unwrap();
+//! assert_eq!(request.id, Some(RequestId::Number(1)));
+//! assert!(!request.is_notification());
+//! ```
+
+use crate::error::HttpError;
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// JSON-RPC protocol version string.
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Invalid JSON was received by the server.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON sent is not a valid request object.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist or is not available.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+/// Internal JSON-RPC error.
+pub const INTERNAL_ERROR: i64 = -32603;
+/// The requested resource does not exist (MCP-defined).
+pub const RESOURCE_NOT_FOUND: i64 = -32002;
+/// The request was refused by server policy (server-defined).
+pub const FORBIDDEN: i64 = -32003;
+
+/// A JSON-RPC request identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RequestId {
+    /// Numeric identifier.
+    Number(i64),
+    /// String identifier.
+    String(String),
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestId::Number(n) => write!(f, "{}", n),
+            RequestId::String(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A JSON-RPC request or notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcRequest {
+    /// Protocol version, always `"2.0"`.
+    pub jsonrpc: String,
+    /// Request identifier; `None` for notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<RequestId>,
+    /// Method name (e.g., `tools/call`).
+    pub method: String,
+    /// Method parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl JsonRpcRequest {
+    /// Returns `true` if this message is a notification (has no `id`).
+    pub fn is_notification(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+/// A JSON-RPC error object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    /// Error code.
+    pub code: i64,
+    /// Short error description.
+    pub message: String,
+    /// Optional additional information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcError {
+    /// Create a new error object.
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// Attach additional data to the error.
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Parse error (-32700).
+    pub fn parse_error(message: impl Into<String>) -> Self {
+        Self::new(PARSE_ERROR, message)
+    }
+
+    /// Invalid request error (-32600).
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(INVALID_REQUEST, message)
+    }
+
+    /// Method not found error (-32601).
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(METHOD_NOT_FOUND, format!("Method '{}' not found", method))
+    }
+
+    /// Invalid params error (-32602).
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+
+    /// Internal error (-32603).
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(INTERNAL_ERROR, message)
+    }
+}
+
+/// Maps HTTP handler errors onto JSON-RPC error codes.
+///
+/// Client errors (400, 404) become `INVALID_PARAMS`, policy refusals (403)
+/// become `FORBIDDEN`, and everything else becomes `INTERNAL_ERROR`. Tool
+/// errors carry their `error_type` (and details) as an object in `data`;
+/// other details are passed as a string.
+impl From<HttpError> for JsonRpcError {
+    fn from(err: HttpError) -> Self {
+        let code = match err.status {
+            StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND => INVALID_PARAMS,
+            StatusCode::FORBIDDEN => FORBIDDEN,
+            _ => INTERNAL_ERROR,
+        };
+        let error = Self::new(code, err.message);
+        match (err.error_type, err.details) {
+            (Some(error_type), details) => {
+                let mut data = serde_json::json!({ "error_type": error_type });
+                if let Some(details) = details {
+                    data["details"] = Value::String(details);
+                }
+                error.with_data(data)
+            }
+            (None, Some(details)) => error.with_data(Value::String(details)),
+            (None, None) => error,
+        }
+    }
+}
+
+/// A JSON-RPC response carrying either a result or an error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    /// Protocol version, always `"2.0"`.
+    pub jsonrpc: String,
+    /// Identifier of the request being answered; `null` if it could not be determined.
+    pub id: Option<RequestId>,
+    /// Successful result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+impl JsonRpcResponse {
+    /// Create a successful response.
+    pub fn success(id: RequestId, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(id),
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Create an error response.
+    pub fn error(id: Option<RequestId>, error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
@@ -61,17 +61,53 @@
 //! # Features
 //!
 //! - HTTP transport with REST endpoints
+//! - JSON-RPC 2.0 endpoint speaking the MCP wire protocol
+//! - `initialize` handshake with capability and protocol-version negotiation
+//! - Streamable HTTP transport with SSE responses and `Mcp-Session-Id` sessions
+//! - stdio transport for running as a local subprocess server
+//! - WebSocket transport (`websocket` feature) with server-initiated requests
+//! - Progress notifications from long-running tools via [`RequestContext`]
+//! - Request id, headers, extensions, authenticated [`Principal`], session, and deadline
+//!   available to every handler through [`RequestContext`]
+//! - Typed shared state for all handlers via [`McpServer::with_state`]
+//! - Pluggable authentication with hashed, constant-time API keys ([`auth`])
+//! - OAuth 2.1 resource-server support: JWTs validated against a JWKS and protected
+//!   resource metadata (`jwt` feature)
+//! - Per-tool, per-prompt, and per-resource-prefix access policies by scope, role, or closure
+//! - Cancellation of in-flight calls by the client or on disconnect
+//! - Resource templates (RFC 6570 level 1) for families of resources
+//! - Text and binary (`blob`) resource contents, several per read
+//! - Resource subscriptions with `notifications/resources/updated`
+//! - Runtime registration through [`ServerHandle`] with `list_changed` notifications
+//! - Sorted, cursor-paginated `*/list` results
+//! - Rich tool results: text, images, audio, embedded resources, and resource links
+//! - Tool failures reported over JSON-RPC as `isError` results the model can see
+//! - Typed tools: argument schemas derived with `schemars`, arguments deserialized with serde
+//! - `#[mcp_tool]`, `#[mcp_resource]`, and `#[mcp_prompt]` attribute macros (`macros` feature)
+//! - Closure-based tools, resources, and prompts via [`McpServer::tool_fn`] and friends
+//! - Tool annotations (read-only, destructive, idempotent, open-world) and a read-only mode
+//! - Structured [`ToolError`]s with stable `error_type` codes and matching status codes
 //! - Trait-based implementation for tools, resources, and prompts
-//! - JSON Schema validation of tool arguments
+//! - JSON Schema validation of tool arguments, and of structured output in debug or strict mode
 //! - Error handling with HTTP status codes
 
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod auth;
 pub mod config;
+pub mod content;
+pub mod context;
 pub mod error;
+pub mod fn_handlers;
+pub mod jsonrpc;
+pub mod lifecycle;
+mod pagination;
 pub mod prompt;
+mod protocol;
+pub mod registry;
 pub mod resource;
+pub mod resource_template;
 /// Schema utilities for extracting JSON Schema from docstrings.
 ///
 /// The `schema` module provides `extract_schema_from_docstring()` which can be used
@@ -80,10 +116,12 @@ pub mod resource;
 /// Example:
 /// ```rust,no_run
 /// use axum_mcp::{Tool, schema::extract_schema_from_docstring};
+/// use async_trait::async_trait;
 /// use serde_json::Value;
 ///
 /// struct MyTool;
 ///
+/// #[async_trait]
 /// impl Tool for MyTool {
 ///     fn schema(&self) -> Value {
 ///         extract_schema_from_docstring(r#"
@@ -92,30 +130,53 @@ pub mod resource;
 ///         "#)
 ///     }
 ///     // ...
+/// #   fn description(&self) -> &str { "my tool" }
+/// #   async fn call(&self, _: &Value) -> Result<Value, String> { Ok(Value::Null) }
 /// }
 /// ```
 pub mod schema;
 pub mod server;
+pub mod session;
 #[cfg(feature = "testing")]
 pub mod testing;
 pub mod tool;
 pub mod tool_error;
+pub mod transport;
+pub mod typed_tool;
 pub mod utils;
 pub mod validation;
 
-// Procedural macros will be in a separate axum-mcp-macros crate
-// #[cfg(feature = "macros")]
-// pub use axum_mcp_macros::{mcp_tool, mcp_resource, mcp_prompt};
+#[cfg(feature = "macros")]
+pub use axum_mcp_macros::{mcp_prompt, mcp_resource, mcp_tool};
+
+/// Support for code generated by the `macros` feature. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::typed_tool::parse_arg;
+    pub use async_trait::async_trait;
+    pub use serde_json;
+}
 
 pub use config::ServerConfig;
-pub use error::{ErrorResponse, HttpError, McpError};
+pub use content::{Annotations, Content, ResourceLink, Role, ToolResult};
+pub use context::{CancellationToken, Principal, ProgressToken, RequestContext};
+pub use error::{ClientRequestError, ErrorResponse, HttpError, McpError};
+pub use fn_handlers::{FnPrompt, FnResource, FnTool, WithContext};
+pub use jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse, RequestId};
+pub use lifecycle::{LoggingLevel, ServerCapabilities, ServerInfo};
 pub use prompt::Prompt;
-pub use resource::Resource;
+pub use registry::ServerHandle;
+pub use resource::{Resource, ResourceContents, ResourceNotifier};
+pub use resource_template::{ResourceTemplate, UriTemplate};
+/// Re-exported so [`TypedTool`] arguments can derive `JsonSchema` with the same version.
+pub use schemars;
 pub use server::McpServer;
+pub use session::{Session, SessionManager};
 #[cfg(feature = "testing")]
 pub use testing::test_tool;
-pub use tool::Tool;
+pub use tool::{Tool, ToolAnnotations, ToolFilter};
 pub use tool_error::{ToolError, ToolErrorResponse};
+pub use typed_tool::TypedTool;
 pub use utils::{
     extract_bool, extract_bool_opt, extract_integer, extract_integer_opt, extract_number,
     extract_number_opt, extract_string, extract_string_opt,
@@ -0,0 +1,168 @@
+//! Connection lifecycle: server identity, capabilities, and protocol versions.
+//!
+//! Every MCP connection starts with an `initialize` request. The server answers
+//! with its [`ServerInfo`], the [`ServerCapabilities`] derived from what is
+//! registered, and the negotiated protocol version.
+//!
+//! The `logging` capability is always advertised: clients pick a minimum
+//! [`LoggingLevel`] with `logging/setLevel`, and handlers send log messages
+//! with [`RequestContext::log`](crate::RequestContext::log).
+//!
+//! # Example
+//!
+//! ```rust
+//! use axum_mcp::{McpServer, ServerInfo};
+//!
+//! let server = McpServer::new().with_server_info(
+//!     ServerInfo::new("weather", "1.0.0").with_instructions("Call `forecast` for a city."),
+//! );
+//! assert_eq!(server.server_info().name, "weather");
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Protocol version preferred by this server.
+pub const LATEST_PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// Protocol versions this server can speak, newest first.
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Pick the protocol version to use for a client.
+///
+/// Returns the client's requested version if it is supported, otherwise the
+/// latest version this server supports. Per the MCP spec, the client decides
+/// whether to continue when the versions differ.
+///
+/// # Examples
+///
+/// ```
+/// use axum_mcp::lifecycle::{negotiate_protocol_version, LATEST_PROTOCOL_VERSION};
+///
+/// assert_eq!(negotiate_protocol_version("2024-11-05"), "2024-11-05");
+/// assert_eq!(negotiate_protocol_version("1999-01-01"), LATEST_PROTOCOL_VERSION);
+/// ```
+pub fn negotiate_protocol_version(requested: &str) -> &'static str {
+    SUPPORTED_PROTOCOL_VERSIONS
+        .iter()
+        .find(|version| **version == requested)
+        .copied()
+        .unwrap_or(LATEST_PROTOCOL_VERSION)
+}
+
+/// Server identity reported during initialization.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerInfo {
+    /// Server name.
+    pub name: String,
+    /// Server version.
+    pub version: String,
+    /// Optional usage instructions for clients (e.g., hints for the model).
+    #[serde(skip)]
+    pub instructions: Option<String>,
+}
+
+impl ServerInfo {
+    /// Create server info with a name and version.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            instructions: None,
+        }
+    }
+
+    /// Set usage instructions for clients.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+}
+
+impl Default for ServerInfo {
+    fn default() -> Self {
+        Self::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+    }
+}
+
+/// Capabilities advertised to clients in the `initialize` response.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ServerCapabilities {
+    /// Present if the server offers tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<ToolsCapability>,
+    /// Present if the server offers resources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourcesCapability>,
+    /// Present if the server offers prompts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompts: Option<PromptsCapability>,
+    /// Present if the server accepts `logging/setLevel` and sends log messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<LoggingCapability>,
+}
+
+/// Tool-related capabilities.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolsCapability {
+    /// Whether the server emits `notifications/tools/list_changed`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub list_changed: bool,
+}
+
+/// Resource-related capabilities.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourcesCapability {
+    /// Whether clients can subscribe to resource updates.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub subscribe: bool,
+    /// Whether the server emits `notifications/resources/list_changed`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub list_changed: bool,
+}
+
+/// Prompt-related capabilities.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptsCapability {
+    /// Whether the server emits `notifications/prompts/list_changed`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub list_changed: bool,
+}
+
+/// Logging capability. Carries no options.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LoggingCapability {}
+
+/// Severity of a log message, as defined by RFC 5424 syslog levels.
+///
+/// Levels are ordered from least (`Debug`) to most (`Emergency`) severe.
+///
+/// # Examples
+///
+/// ```
+/// use axum_mcp::LoggingLevel;
+///
+/// assert!(LoggingLevel::Error > LoggingLevel::Warning);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoggingLevel {
+    /// Detailed debugging information.
+    Debug,
+    /// General informational messages.
+    Info,
+    /// Normal but significant events.
+    Notice,
+    /// Warning conditions.
+    Warning,
+    /// Error conditions.
+    Error,
+    /// Critical conditions.
+    Critical,
+    /// Action must be taken immediately.
+    Alert,
+    /// The system is unusable.
+    Emergency,
+}
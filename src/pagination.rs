@@ -0,0 +1,52 @@
+//! Cursor-based pagination for list results.
+//!
+//! Lists are sorted by their key (tool name, resource URI, template, prompt
+//! name) so that pages are stable between requests. A cursor is the opaque,
+//! base64-encoded key of the last item on the previous page; paging by key
+//! rather than offset keeps the remaining pages consistent when entries are
+//! added or removed in between.
+
+use crate::error::HttpError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+/// One page of a sorted list.
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    /// Cursor for the following page, if there is one.
+    pub(crate) next_cursor: Option<String>,
+}
+
+/// Sort `items` by key and return the page following `cursor`.
+///
+/// # Errors
+///
+/// Returns a bad request error if `cursor` was not issued by this server.
+pub(crate) fn paginate<T>(
+    mut items: Vec<(String, T)>,
+    cursor: Option<&str>,
+    page_size: usize,
+) -> Result<Page<T>, HttpError> {
+    items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let start = match cursor {
+        Some(cursor) => {
+            let after = decode_cursor(cursor)
+                .ok_or_else(|| HttpError::bad_request(format!("Invalid cursor '{}'", cursor)))?;
+            items.partition_point(|(key, _)| *key <= after)
+        }
+        None => 0,
+    };
+    let end = start.saturating_add(page_size.max(1)).min(items.len());
+    let next_cursor = (end < items.len()).then(|| encode_cursor(&items[end - 1].0));
+    let items = items.drain(start..end).map(|(_, item)| item).collect();
+    Ok(Page { items, next_cursor })
+}
+
+fn encode_cursor(key: &str) -> String {
+    URL_SAFE_NO_PAD.encode(key)
+}
+
+fn decode_cursor(cursor: &str) -> Option<String> {
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+    String::from_utf8(bytes).ok()
+}
@@ -38,6 +38,7 @@
 //! }
 //! ```
 
+use crate::context::RequestContext;
 use async_trait::async_trait;
 use serde_json::Value;
 
@@ -110,4 +111,18 @@ pub trait Prompt: Send + Sync {
     /// # }
     /// ```
     async fn render(&self, arguments: &Value) -> Result<String, String>;
+
+    /// Render the prompt with access to the request context.
+    ///
+    /// This is what the server invokes. The default implementation ignores the
+    /// context and delegates to [`render`](Prompt::render); override it to
+    /// tailor the prompt to the caller, e.g. via [`RequestContext::principal`].
+    async fn render_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<String, String> {
+        let _ = ctx;
+        self.render(arguments).await
+    }
 }
@@ -0,0 +1,287 @@
+//! MCP method dispatch over JSON-RPC 2.0.
+//!
+//! Maps JSON-RPC method names onto the same tool, resource, and prompt registries
+//! used by the REST routes, so every transport shares one implementation.
+
+use crate::context::{CancellationToken, HttpParts, Outlet, ProgressToken};
+use crate::error::HttpError;
+use crate::jsonrpc::{
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse, RequestId, JSONRPC_VERSION, RESOURCE_NOT_FOUND,
+};
+use crate::lifecycle::{negotiate_protocol_version, LoggingLevel};
+use crate::server::{tool_result_json, McpServer};
+use crate::session::Session;
+use axum::http::StatusCode;
+use serde_json::Value;
+use std::sync::Arc;
+
+impl McpServer {
+    /// Handle a single raw JSON-RPC message.
+    ///
+    /// Returns `None` for notifications and for the client's responses to
+    /// server-initiated requests, which never receive a response.
+    ///
+    /// `outlet` receives messages related to the request while it runs (such as
+    /// progress notifications); with `None` they are discarded. `http` is the
+    /// HTTP request the message arrived with, exposed to handlers through their
+    /// [`RequestContext`](crate::RequestContext).
+    pub(crate) async fn handle_jsonrpc(
+        &self,
+        session: &Arc<Session>,
+        message: Value,
+        outlet: Option<Outlet>,
+        http: HttpParts,
+    ) -> Option<JsonRpcResponse> {
+        if is_client_response(&message) {
+            match serde_json::from_value::<JsonRpcResponse>(message) {
+                Ok(response) => session.resolve(response),
+                Err(e) => tracing::warn!("Ignoring malformed client response: {}", e),
+            }
+            return None;
+        }
+
+        let request = match parse_request(message) {
+            Ok(request) => request,
+            Err((id, error)) => return Some(JsonRpcResponse::error(id, error)),
+        };
+
+        let Some(id) = request.id.clone() else {
+            self.handle_notification(session, &request);
+            return None;
+        };
+
+        // The handshake cannot be cancelled; everything else is tracked so that
+        // `notifications/cancelled` can abort it
+        let in_flight = (request.method != "initialize").then(|| session.begin_request(id.clone()));
+        let token = in_flight
+            .as_ref()
+            .map(|in_flight| in_flight.token().clone())
+            .unwrap_or_default();
+
+        let result = tokio::select! {
+            result = self.dispatch(session, &request, outlet, http, token.clone()) => result,
+            _ = token.cancelled() => {
+                // Cancelled requests are not answered
+                tracing::debug!(id = %id, method = %request.method, "request cancelled");
+                return None;
+            }
+        };
+        if let Some(in_flight) = in_flight {
+            in_flight.finish();
+        }
+        Some(match result {
+            Ok(result) => JsonRpcResponse::success(id, result),
+            Err(error) => JsonRpcResponse::error(Some(id), error),
+        })
+    }
+
+    async fn dispatch(
+        &self,
+        session: &Arc<Session>,
+        request: &JsonRpcRequest,
+        outlet: Option<Outlet>,
+        http: HttpParts,
+        cancellation: CancellationToken,
+    ) -> Result<Value, JsonRpcError> {
+        let params = request
+            .params
+            .clone()
+            .unwrap_or_else(|| serde_json::json!({}));
+        if !params.is_object() {
+            return Err(JsonRpcError::invalid_params(
+                "Method parameters must be an object",
+            ));
+        }
+
+        // Only the handshake itself and pings may precede initialization
+        let method = request.method.as_str();
+        if !session.is_initialized() && method != "initialize" && method != "ping" {
+            return Err(JsonRpcError::invalid_request(format!(
+                "Received '{}' before initialization; send 'initialize' first",
+                method
+            )));
+        }
+
+        let ctx = self
+            .request_context()
+            .with_progress_token(progress_token(&params))
+            .with_outlet(outlet)
+            .with_cancellation(cancellation)
+            .with_http(http)
+            .with_session(Arc::clone(session));
+
+        match method {
+            "initialize" => self.handle_initialize(session, &params),
+            "ping" => Ok(serde_json::json!({})),
+            "tools/list" => Ok(self.handle_list_tools(list_cursor(&params)?, &ctx)?),
+            "tools/call" => {
+                let result = self.handle_call_tool(&params, &ctx).await?;
+                Ok(tool_result_json(result)?)
+            }
+            "resources/list" => Ok(self.handle_list_resources(list_cursor(&params)?, &ctx)?),
+            "resources/templates/list" => {
+                Ok(self.handle_list_resource_templates(list_cursor(&params)?, &ctx)?)
+            }
+            "resources/subscribe" => {
+                let uri = subscription_uri(&params)?;
+                self.check_subscribable(uri, &ctx).map_err(resource_error)?;
+                session.subscribe_resource(uri);
+                Ok(serde_json::json!({}))
+            }
+            "resources/unsubscribe" => {
+                session.unsubscribe_resource(subscription_uri(&params)?);
+                Ok(serde_json::json!({}))
+            }
+            "resources/read" => self
+                .handle_read_resource(&params, &ctx)
+                .await
+                .map_err(resource_error),
+            "prompts/list" => Ok(self.handle_list_prompts(list_cursor(&params)?, &ctx)?),
+            "prompts/get" => Ok(self.handle_get_prompt(&params, &ctx).await?),
+            "logging/setLevel" => {
+                session.set_log_level(logging_level(&params)?);
+                Ok(serde_json::json!({}))
+            }
+            method => Err(JsonRpcError::method_not_found(method)),
+        }
+    }
+
+    fn handle_initialize(&self, session: &Session, params: &Value) -> Result<Value, JsonRpcError> {
+        let requested = params
+            .get("protocolVersion")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JsonRpcError::invalid_params("Missing 'protocolVersion' field"))?;
+        let protocol_version = negotiate_protocol_version(requested);
+        if protocol_version != requested {
+            tracing::info!(
+                "Client requested unsupported protocol version '{}', offering '{}'",
+                requested,
+                protocol_version
+            );
+        }
+
+        session.initialize(
+            protocol_version,
+            params.get("clientInfo").cloned(),
+            params.get("capabilities").cloned(),
+        );
+
+        let mut result = serde_json::json!({
+            "protocolVersion": protocol_version,
+            "capabilities": self.capabilities(),
+            "serverInfo": self.server_info(),
+        });
+        if let Some(instructions) = &self.server_info().instructions {
+            result["instructions"] = Value::String(instructions.clone());
+        }
+        Ok(result)
+    }
+
+    fn handle_notification(&self, session: &Session, request: &JsonRpcRequest) {
+        match request.method.as_str() {
+            "notifications/initialized" => session.mark_client_ready(),
+            "notifications/cancelled" => {
+                let params = request.params.as_ref();
+                let Some(id) = params
+                    .and_then(|p| p.get("requestId"))
+                    .and_then(|id| serde_json::from_value::<RequestId>(id.clone()).ok())
+                else {
+                    tracing::debug!("Ignoring cancellation without a valid requestId");
+                    return;
+                };
+                let reason = params
+                    .and_then(|p| p.get("reason"))
+                    .and_then(|r| r.as_str())
+                    .unwrap_or("none given");
+                if session.cancel_request(&id) {
+                    tracing::debug!(id = %id, reason = %reason, "client cancelled request");
+                } else {
+                    // The request may already have finished
+                    tracing::debug!(id = %id, "Ignoring cancellation of unknown request");
+                }
+            }
+            method => tracing::debug!(method = %method, "received notification"),
+        }
+    }
+}
+
+/// A message without a method that carries a result or error answers one of our requests.
+pub(crate) fn is_client_response(message: &Value) -> bool {
+    message.get("method").is_none()
+        && (message.get("result").is_some() || message.get("error").is_some())
+}
+
+fn subscription_uri(params: &Value) -> Result<&str, JsonRpcError> {
+    params
+        .get("uri")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| JsonRpcError::invalid_params("Missing 'uri' field"))
+}
+
+/// The `level` of a `logging/setLevel` request.
+fn logging_level(params: &Value) -> Result<LoggingLevel, JsonRpcError> {
+    let level = params
+        .get("level")
+        .ok_or_else(|| JsonRpcError::invalid_params("Missing 'level' field"))?;
+    serde_json::from_value(level.clone())
+        .map_err(|_| JsonRpcError::invalid_params(format!("Invalid log level: {}", level)))
+}
+
+/// The `cursor` of a list request, if the client is asking for a later page.
+fn list_cursor(params: &Value) -> Result<Option<&str>, JsonRpcError> {
+    match params.get("cursor") {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(cursor)) => Ok(Some(cursor)),
+        Some(_) => Err(JsonRpcError::invalid_params("'cursor' must be a string")),
+    }
+}
+
+/// The `_meta.progressToken` a client attached to a request, if any.
+fn progress_token(params: &Value) -> Option<ProgressToken> {
+    params
+        .get("_meta")
+        .and_then(|meta| meta.get("progressToken"))
+        .and_then(|token| serde_json::from_value(token.clone()).ok())
+}
+
+/// Validate the JSON-RPC envelope of an incoming message.
+///
+/// On failure, returns the request id (if recoverable) along with the error.
+fn parse_request(message: Value) -> Result<JsonRpcRequest, (Option<RequestId>, JsonRpcError)> {
+    // Recover the id (if any) so errors can still be correlated by the client
+    let id = message
+        .get("id")
+        .cloned()
+        .and_then(|id| serde_json::from_value::<RequestId>(id).ok());
+
+    if message.is_array() {
+        return Err((
+            None,
+            JsonRpcError::invalid_request("Batch requests are not supported"),
+        ));
+    }
+
+    let request: JsonRpcRequest = serde_json::from_value(message)
+        .map_err(|e| (id.clone(), JsonRpcError::invalid_request(e.to_string())))?;
+
+    if request.jsonrpc != JSONRPC_VERSION {
+        return Err((
+            id,
+            JsonRpcError::invalid_request(format!(
+                "Unsupported JSON-RPC version '{}', expected '{}'",
+                request.jsonrpc, JSONRPC_VERSION
+            )),
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Resource lookups that miss use the MCP-specific "resource not found" code.
+fn resource_error(err: HttpError) -> JsonRpcError {
+    if err.status == StatusCode::NOT_FOUND {
+        JsonRpcError::new(RESOURCE_NOT_FOUND, err.message)
+    } else {
+        err.into()
+    }
+}
@@ -0,0 +1,385 @@
+//! Registries of tools, resources, and prompts.
+//!
+//! The registries live behind a shared lock so they can change while the
+//! server is running. A [`ServerHandle`], obtained with
+//! [`McpServer::handle`](crate::McpServer::handle) before the server is turned
+//! into a router, adds and removes entries and tells connected clients about it
+//! with `notifications/tools/list_changed`, `notifications/resources/list_changed`
+//! or `notifications/prompts/list_changed`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use axum_mcp::{McpServer, Tool};
+//! use async_trait::async_trait;
+//! use serde_json::Value;
+//!
+//! struct GithubSearch;
+//!
+//! #[async_trait]
+//! impl Tool for GithubSearch {
+//!     fn description(&self) -> &str { "Search GitHub issues" }
+//!     fn schema(&self) -> Value { serde_json::json!({ "type": "object" }) }
+//!     async fn call(&self, _: &Value) -> Result<Value, String> { Ok(Value::Null) }
+//! }
+//!
+//! # async fn example() -> Result<(), axum_mcp::McpError> {
+//! let server = McpServer::new();
+//! let handle = server.handle();
+//! tokio::spawn(server.serve("127.0.0.1:8080"));
+//!
+//! // Later, when the user connects the integration:
+//! handle.add_tool("github_search", GithubSearch)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::auth::AccessPolicy;
+use crate::context::RequestContext;
+use crate::error::McpError;
+use crate::prompt::Prompt;
+use crate::resource::Resource;
+use crate::resource_template::{ResourceTemplate, UriTemplate};
+use crate::session::SessionManager;
+use crate::tool::Tool;
+use crate::validation::{validate_prompt_name, validate_resource_uri, validate_tool_name};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// The tools, resources, resource templates, and prompts a server offers.
+#[derive(Clone, Default)]
+pub(crate) struct Registry {
+    pub(crate) tools: HashMap<String, Arc<dyn Tool>>,
+    pub(crate) resources: HashMap<String, Arc<dyn Resource>>,
+    pub(crate) resource_templates: Vec<RegisteredTemplate>,
+    pub(crate) prompts: HashMap<String, Arc<dyn Prompt>>,
+    /// Access policies of the tools that have one, by tool name.
+    pub(crate) tool_policies: HashMap<String, AccessPolicy>,
+    /// Access policies of the prompts that have one, by prompt name.
+    pub(crate) prompt_policies: HashMap<String, AccessPolicy>,
+    /// Access policies of resources, by URI prefix.
+    pub(crate) resource_policies: Vec<(String, AccessPolicy)>,
+    /// Set once a [`ServerHandle`] exists, i.e. the lists may change at runtime.
+    pub(crate) dynamic: bool,
+}
+
+/// A resource template together with its handler.
+#[derive(Clone)]
+pub(crate) struct RegisteredTemplate {
+    pub(crate) template: UriTemplate,
+    pub(crate) handler: Arc<dyn ResourceTemplate>,
+}
+
+impl Registry {
+    pub(crate) fn insert_tool(
+        &mut self,
+        name: String,
+        tool: Arc<dyn Tool>,
+        policy: Option<AccessPolicy>,
+    ) -> Result<(), McpError> {
+        validate_tool_name(&name)
+            .map_err(|e| McpError::Validation(format!("Invalid tool name '{}': {}", name, e)))?;
+        match policy {
+            Some(policy) => self.tool_policies.insert(name.clone(), policy),
+            None => self.tool_policies.remove(&name),
+        };
+        self.tools.insert(name, tool);
+        Ok(())
+    }
+
+    pub(crate) fn remove_tool(&mut self, name: &str) -> bool {
+        self.tool_policies.remove(name);
+        self.tools.remove(name).is_some()
+    }
+
+    pub(crate) fn insert_resource(
+        &mut self,
+        uri: String,
+        resource: Arc<dyn Resource>,
+    ) -> Result<(), McpError> {
+        validate_resource_uri(&uri)
+            .map_err(|e| McpError::Validation(format!("Invalid resource URI '{}': {}", uri, e)))?;
+        self.resources.insert(uri, resource);
+        Ok(())
+    }
+
+    pub(crate) fn insert_resource_template(
+        &mut self,
+        template: String,
+        handler: Arc<dyn ResourceTemplate>,
+    ) -> Result<(), McpError> {
+        validate_resource_uri(&template).map_err(|e| {
+            McpError::Validation(format!("Invalid resource template '{}': {}", template, e))
+        })?;
+        let template = UriTemplate::parse(&template).map_err(McpError::Validation)?;
+        match self
+            .resource_templates
+            .iter_mut()
+            .find(|registered| registered.template == template)
+        {
+            Some(registered) => registered.handler = handler,
+            None => self
+                .resource_templates
+                .push(RegisteredTemplate { template, handler }),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn insert_prompt(
+        &mut self,
+        name: String,
+        prompt: Arc<dyn Prompt>,
+        policy: Option<AccessPolicy>,
+    ) -> Result<(), McpError> {
+        validate_prompt_name(&name)
+            .map_err(|e| McpError::Validation(format!("Invalid prompt name '{}': {}", name, e)))?;
+        match policy {
+            Some(policy) => self.prompt_policies.insert(name.clone(), policy),
+            None => self.prompt_policies.remove(&name),
+        };
+        self.prompts.insert(name, prompt);
+        Ok(())
+    }
+
+    pub(crate) fn remove_prompt(&mut self, name: &str) -> bool {
+        self.prompt_policies.remove(name);
+        self.prompts.remove(name).is_some()
+    }
+
+    /// Whether `ctx` may list and call the tool `name`.
+    pub(crate) fn tool_allowed(&self, name: &str, ctx: &RequestContext) -> bool {
+        self.tool_policies
+            .get(name)
+            .map_or(true, |policy| policy.allows(ctx))
+    }
+
+    /// Whether `ctx` may list and read `uri` (or a template starting like it):
+    /// every policy whose prefix it starts with must allow it.
+    pub(crate) fn resource_allowed(&self, uri: &str, ctx: &RequestContext) -> bool {
+        self.resource_policies
+            .iter()
+            .filter(|(prefix, _)| uri.starts_with(prefix.as_str()))
+            .all(|(_, policy)| policy.allows(ctx))
+    }
+
+    /// Whether `ctx` may list and get the prompt `name`.
+    pub(crate) fn prompt_allowed(&self, name: &str, ctx: &RequestContext) -> bool {
+        self.prompt_policies
+            .get(name)
+            .map_or(true, |policy| policy.allows(ctx))
+    }
+
+    /// Find the most specific template matching `uri`.
+    ///
+    /// The template with the most literal characters wins; ties go to the one
+    /// registered first.
+    pub(crate) fn match_resource_template(
+        &self,
+        uri: &str,
+    ) -> Option<(&RegisteredTemplate, HashMap<String, String>)> {
+        let mut best: Option<(&RegisteredTemplate, HashMap<String, String>)> = None;
+        for registered in &self.resource_templates {
+            let Some(variables) = registered.template.match_uri(uri) else {
+                continue;
+            };
+            let more_specific = best.as_ref().map_or(true, |(current, _)| {
+                registered.template.literal_len() > current.template.literal_len()
+            });
+            if more_specific {
+                best = Some((registered, variables));
+            }
+        }
+        best
+    }
+}
+
+/// Lock guarding a server's registry, shared with its [`ServerHandle`]s.
+pub(crate) type SharedRegistry = Arc<RwLock<Registry>>;
+
+pub(crate) fn read(registry: &RwLock<Registry>) -> RwLockReadGuard<'_, Registry> {
+    registry.read().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) fn write(registry: &RwLock<Registry>) -> RwLockWriteGuard<'_, Registry> {
+    registry.write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Handle for changing a server's tools, resources, and prompts while it runs.
+///
+/// Every change is announced to initialized sessions with the matching
+/// `list_changed` notification. Obtain one with
+/// [`McpServer::handle`](crate::McpServer::handle); it is cheap to clone.
+#[derive(Clone)]
+pub struct ServerHandle {
+    registry: SharedRegistry,
+    sessions: Arc<SessionManager>,
+}
+
+impl ServerHandle {
+    pub(crate) fn new(registry: SharedRegistry, sessions: Arc<SessionManager>) -> Self {
+        write(&registry).dynamic = true;
+        Self { registry, sessions }
+    }
+
+    /// Add a tool, replacing any tool with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    pub fn add_tool(
+        &self,
+        name: impl Into<String>,
+        tool: impl Tool + 'static,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_tool(name.into(), Arc::new(tool), None)?;
+        self.notify_list_changed("tools");
+        Ok(())
+    }
+
+    /// Add a tool that only callers allowed by `policy` can list and call,
+    /// replacing any tool with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    pub fn add_tool_with_policy(
+        &self,
+        name: impl Into<String>,
+        tool: impl Tool + 'static,
+        policy: AccessPolicy,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_tool(name.into(), Arc::new(tool), Some(policy))?;
+        self.notify_list_changed("tools");
+        Ok(())
+    }
+
+    /// Remove a tool, returning whether it was registered.
+    pub fn remove_tool(&self, name: &str) -> bool {
+        let removed = write(&self.registry).remove_tool(name);
+        if removed {
+            self.notify_list_changed("tools");
+        }
+        removed
+    }
+
+    /// Add a resource, replacing any resource with the same URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the resource URI is invalid.
+    pub fn add_resource(
+        &self,
+        uri: impl Into<String>,
+        resource: impl Resource + 'static,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_resource(uri.into(), Arc::new(resource))?;
+        self.notify_list_changed("resources");
+        Ok(())
+    }
+
+    /// Remove a resource, returning whether it was registered.
+    pub fn remove_resource(&self, uri: &str) -> bool {
+        let removed = write(&self.registry).resources.remove(uri).is_some();
+        if removed {
+            self.notify_list_changed("resources");
+        }
+        removed
+    }
+
+    /// Add a resource template, replacing any with the same template string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the template is invalid.
+    pub fn add_resource_template(
+        &self,
+        template: impl Into<String>,
+        handler: impl ResourceTemplate + 'static,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_resource_template(template.into(), Arc::new(handler))?;
+        self.notify_list_changed("resources");
+        Ok(())
+    }
+
+    /// Remove a resource template, returning whether it was registered.
+    pub fn remove_resource_template(&self, template: &str) -> bool {
+        let removed = {
+            let mut registry = write(&self.registry);
+            let before = registry.resource_templates.len();
+            registry
+                .resource_templates
+                .retain(|registered| registered.template.as_str() != template);
+            registry.resource_templates.len() != before
+        };
+        if removed {
+            self.notify_list_changed("resources");
+        }
+        removed
+    }
+
+    /// Add a prompt, replacing any prompt with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    pub fn add_prompt(
+        &self,
+        name: impl Into<String>,
+        prompt: impl Prompt + 'static,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_prompt(name.into(), Arc::new(prompt), None)?;
+        self.notify_list_changed("prompts");
+        Ok(())
+    }
+
+    /// Add a prompt that only callers allowed by `policy` can list and get,
+    /// replacing any prompt with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    pub fn add_prompt_with_policy(
+        &self,
+        name: impl Into<String>,
+        prompt: impl Prompt + 'static,
+        policy: AccessPolicy,
+    ) -> Result<(), McpError> {
+        write(&self.registry).insert_prompt(name.into(), Arc::new(prompt), Some(policy))?;
+        self.notify_list_changed("prompts");
+        Ok(())
+    }
+
+    /// Remove a prompt, returning whether it was registered.
+    pub fn remove_prompt(&self, name: &str) -> bool {
+        let removed = write(&self.registry).remove_prompt(name);
+        if removed {
+            self.notify_list_changed("prompts");
+        }
+        removed
+    }
+
+    /// Tell initialized sessions that the `kind` list changed.
+    fn notify_list_changed(&self, kind: &str) {
+        let method = format!("notifications/{}/list_changed", kind);
+        let delivered = self
+            .sessions
+            .sessions()
+            .iter()
+            .filter(|session| session.is_initialized())
+            .filter(|session| session.notify(&method, serde_json::json!({})))
+            .count();
+        tracing::debug!("Sent {} to {} session(s)", method, delivered);
+    }
+}
+
+impl std::fmt::Debug for ServerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let registry = read(&self.registry);
+        f.debug_struct("ServerHandle")
+            .field("tools", &registry.tools.len())
+            .field("resources", &registry.resources.len())
+            .field("resource_templates", &registry.resource_templates.len())
+            .field("prompts", &registry.prompts.len())
+            .finish()
+    }
+}
@@ -30,8 +30,105 @@
 //!     }
 //! }
 //! ```
+//!
+//! Binary data and resources with several parts implement
+//! [`Resource::read_contents`] instead, returning [`ResourceContents`] items.
 
+use crate::context::RequestContext;
+use crate::session::SessionManager;
 use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// One item of content returned by a resource read.
+///
+/// Serializes to the MCP `TextResourceContents` / `BlobResourceContents` shapes.
+///
+/// # Example
+///
+/// ```
+/// use axum_mcp::ResourceContents;
+///
+/// let text = ResourceContents::text("file:///notes.md", "text/markdown", "# Notes");
+/// let image = ResourceContents::blob("file:///logo.png", "image/png", [0x89, b'P', b'N', b'G']);
+/// assert_eq!(image.uri(), "file:///logo.png");
+/// assert_eq!(image.decode_blob().unwrap(), vec![0x89, b'P', b'N', b'G']);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResourceContents {
+    /// Text content.
+    Text {
+        /// URI of this content item.
+        uri: String,
+        /// MIME type of the text.
+        #[serde(rename = "mimeType", default, skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        /// The text itself.
+        text: String,
+    },
+    /// Binary content, base64-encoded.
+    Blob {
+        /// URI of this content item.
+        uri: String,
+        /// MIME type of the data.
+        #[serde(rename = "mimeType", default, skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        /// Base64-encoded data.
+        blob: String,
+    },
+}
+
+impl ResourceContents {
+    /// Create text content.
+    pub fn text(
+        uri: impl Into<String>,
+        mime_type: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self::Text {
+            uri: uri.into(),
+            mime_type: Some(mime_type.into()),
+            text: text.into(),
+        }
+    }
+
+    /// Create binary content, base64-encoding `data`.
+    pub fn blob(
+        uri: impl Into<String>,
+        mime_type: impl Into<String>,
+        data: impl AsRef<[u8]>,
+    ) -> Self {
+        Self::Blob {
+            uri: uri.into(),
+            mime_type: Some(mime_type.into()),
+            blob: base64::engine::general_purpose::STANDARD.encode(data),
+        }
+    }
+
+    /// URI of this content item.
+    pub fn uri(&self) -> &str {
+        match self {
+            Self::Text { uri, .. } | Self::Blob { uri, .. } => uri,
+        }
+    }
+
+    /// MIME type of this content item, if known.
+    pub fn mime_type(&self) -> Option<&str> {
+        match self {
+            Self::Text { mime_type, .. } | Self::Blob { mime_type, .. } => mime_type.as_deref(),
+        }
+    }
+
+    /// Decode the data of blob content; `None` for text or invalid base64.
+    pub fn decode_blob(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Blob { blob, .. } => base64::engine::general_purpose::STANDARD.decode(blob).ok(),
+            Self::Text { .. } => None,
+        }
+    }
+}
 
 /// A resource that can be accessed by MCP clients.
 ///
@@ -64,7 +161,7 @@ pub trait Resource: Send + Sync {
     /// See [IANA Media Types](https://www.iana.org/assignments/media-types/) for standard types.
     fn mime_type(&self) -> &str;
 
-    /// Read the resource's content.
+    /// Read the resource's content as text.
     ///
     /// # Returns
     ///
@@ -73,7 +170,104 @@ pub trait Resource: Send + Sync {
     ///
     /// # Note
     ///
-    /// The content is returned as a string regardless of MIME type. For binary data,
-    /// consider base64 encoding or using a text-based representation.
+    /// For binary data or multiple content items, also implement
+    /// [`read_contents`](Resource::read_contents), which the server calls
+    /// instead; this method can then return an error.
     async fn read(&self) -> Result<String, String>;
+
+    /// Read the resource's contents.
+    ///
+    /// `uri` is the URI the client asked for. The default implementation wraps
+    /// [`read`](Resource::read) in a single text item with this resource's MIME
+    /// type; override it to return binary blobs or several items, e.g. one per
+    /// file of a directory.
+    async fn read_contents(&self, uri: &str) -> Result<Vec<ResourceContents>, String> {
+        let text = self.read().await?;
+        Ok(vec![ResourceContents::text(uri, self.mime_type(), text)])
+    }
+
+    /// Read the resource's contents with access to the request context.
+    ///
+    /// This is what the server invokes. The default implementation ignores the
+    /// context and delegates to [`read_contents`](Resource::read_contents);
+    /// override it to depend on the caller, e.g. via [`RequestContext::principal`].
+    async fn read_with_context(
+        &self,
+        uri: &str,
+        ctx: &RequestContext,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let _ = ctx;
+        self.read_contents(uri).await
+    }
+
+    /// Whether clients may subscribe to updates of this resource.
+    ///
+    /// Return `true` only if the resource signals its changes through a
+    /// [`ResourceNotifier`]. Defaults to `false`.
+    fn subscribable(&self) -> bool {
+        false
+    }
+}
+
+/// Handle for announcing that a resource has changed.
+///
+/// Sends `notifications/resources/updated` to every session subscribed to the
+/// URI. Obtain one with [`McpServer::resource_notifier`](crate::McpServer::resource_notifier)
+/// and hand it to the resources that wrap live data; it is cheap to clone.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_mcp::{McpServer, Resource, ResourceNotifier};
+/// use async_trait::async_trait;
+///
+/// struct BuildStatus {
+///     notifier: ResourceNotifier,
+/// }
+///
+/// #[async_trait]
+/// impl Resource for BuildStatus {
+///     fn name(&self) -> &str { "Build status" }
+///     fn description(&self) -> &str { "Status of the latest CI build" }
+///     fn mime_type(&self) -> &str { "text/plain" }
+///     async fn read(&self) -> Result<String, String> { Ok("passing".to_string()) }
+///     fn subscribable(&self) -> bool { true }
+/// }
+///
+/// # fn example() -> Result<(), axum_mcp::McpError> {
+/// let server = McpServer::new();
+/// let notifier = server.resource_notifier();
+/// let server = server.resource("ci://build/status", BuildStatus { notifier: notifier.clone() })?;
+///
+/// // Later, when the build finishes:
+/// notifier.notify_updated("ci://build/status");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResourceNotifier {
+    sessions: Arc<SessionManager>,
+}
+
+impl ResourceNotifier {
+    pub(crate) fn new(sessions: Arc<SessionManager>) -> Self {
+        Self { sessions }
+    }
+
+    /// Notify subscribers that the resource at `uri` changed.
+    ///
+    /// Returns the number of sessions the notification was delivered to.
+    pub fn notify_updated(&self, uri: &str) -> usize {
+        self.sessions
+            .sessions()
+            .iter()
+            .filter(|session| session.is_subscribed(uri))
+            .filter(|session| {
+                session.notify(
+                    "notifications/resources/updated",
+                    serde_json::json!({ "uri": uri }),
+                )
+            })
+            .count()
+    }
 }
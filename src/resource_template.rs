@@ -0,0 +1,378 @@
+//! Resource templates for MCP.
+//!
+//! A resource template exposes a family of resources under one
+//! [RFC 6570](https://www.rfc-editor.org/rfc/rfc6570) level-1 URI template, such
+//! as `db://users/{id}`. When a client reads a URI that no fixed resource
+//! matches, the server tries each template and passes the extracted variables
+//! to [`ResourceTemplate::read`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use axum_mcp::ResourceTemplate;
+//! use async_trait::async_trait;
+//! use std::collections::HashMap;
+//!
+//! struct UserResource;
+//!
+//! #[async_trait]
+//! impl ResourceTemplate for UserResource {
+//!     fn name(&self) -> &str {
+//!         "User record"
+//!     }
+//!
+//!     fn description(&self) -> &str {
+//!         "A user looked up by id"
+//!     }
+//!
+//!     fn mime_type(&self) -> &str {
+//!         "application/json"
+//!     }
+//!
+//!     async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String> {
+//!         let id = &variables["id"];
+//!         Ok(format!(r#"{{"id": "{}"}}"#, id))
+//!     }
+//! }
+//! ```
+
+use crate::context::RequestContext;
+use crate::resource::ResourceContents;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A family of resources addressed by a URI template.
+///
+/// Register with [`McpServer::register_resource_template`](crate::McpServer::register_resource_template).
+#[async_trait]
+pub trait ResourceTemplate: Send + Sync {
+    /// Get the display name shared by resources of this template.
+    fn name(&self) -> &str;
+
+    /// Get the template's description.
+    fn description(&self) -> &str;
+
+    /// Get the MIME type of resources of this template.
+    fn mime_type(&self) -> &str;
+
+    /// Read the resource identified by the template variables as text.
+    ///
+    /// `variables` maps each variable name in the template to its
+    /// percent-decoded value from the requested URI.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The resource content as a string
+    /// * `Err(String)` - An error message if the resource cannot be read
+    ///
+    /// # Note
+    ///
+    /// For binary data or multiple content items, also implement
+    /// [`read_contents`](ResourceTemplate::read_contents), which the server
+    /// calls instead; this method can then return an error.
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String>;
+
+    /// Read the contents of the resource at `uri`.
+    ///
+    /// The default implementation wraps
+    /// [`read`](ResourceTemplate::read) in a single text item; override it to
+    /// return binary blobs or several items.
+    async fn read_contents(
+        &self,
+        uri: &str,
+        variables: &HashMap<String, String>,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let text = self.read(variables).await?;
+        Ok(vec![ResourceContents::text(uri, self.mime_type(), text)])
+    }
+
+    /// Read the contents of the resource at `uri` with access to the request
+    /// context.
+    ///
+    /// This is what the server invokes. The default implementation ignores the
+    /// context and delegates to [`read_contents`](ResourceTemplate::read_contents).
+    async fn read_with_context(
+        &self,
+        uri: &str,
+        variables: &HashMap<String, String>,
+        ctx: &RequestContext,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let _ = ctx;
+        self.read_contents(uri, variables).await
+    }
+
+    /// Whether clients may subscribe to updates of resources of this template.
+    ///
+    /// See [`Resource::subscribable`](crate::Resource::subscribable). Defaults to `false`.
+    fn subscribable(&self) -> bool {
+        false
+    }
+}
+
+/// A parsed RFC 6570 level-1 URI template, e.g. `file:///{path}` or `db://users/{id}`.
+///
+/// Only simple string expansion (`{var}`) is supported. A variable matches one
+/// or more characters other than `/`, `?` and `#`, so it spans a single path
+/// segment; adjacent variables (`{a}{b}`) are rejected as ambiguous.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UriTemplate {
+    template: String,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Variable(String),
+}
+
+impl UriTemplate {
+    /// Parse a URI template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message for unbalanced braces, empty or invalid
+    /// variable names, operators from higher template levels, repeated
+    /// variables, and adjacent variables.
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                return Err(format!("Unmatched '}}' in URI template '{}'", template));
+            }
+            if open > 0 {
+                parts.push(Part::Literal(rest[..open].to_string()));
+            }
+            let close = rest[open..]
+                .find('}')
+                .map(|i| open + i)
+                .ok_or_else(|| format!("Unclosed '{{' in URI template '{}'", template))?;
+            let name = &rest[open + 1..close];
+            validate_variable_name(name)
+                .map_err(|e| format!("Invalid URI template '{}': {}", template, e))?;
+
+            if matches!(parts.last(), Some(Part::Variable(_))) {
+                return Err(format!(
+                    "Invalid URI template '{}': adjacent variables cannot be matched unambiguously",
+                    template
+                ));
+            }
+            if parts
+                .iter()
+                .any(|p| matches!(p, Part::Variable(existing) if existing == name))
+            {
+                return Err(format!(
+                    "Invalid URI template '{}': variable '{}' appears more than once",
+                    template, name
+                ));
+            }
+            parts.push(Part::Variable(name.to_string()));
+            rest = &rest[close + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Literal(rest.to_string()));
+        }
+
+        Ok(Self {
+            template: template.to_string(),
+            parts,
+        })
+    }
+
+    /// The template string as registered.
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Names of the template's variables, in order of appearance.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Variable(name) => Some(name.as_str()),
+            Part::Literal(_) => None,
+        })
+    }
+
+    /// Match a URI against the template, returning the decoded variable values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axum_mcp::UriTemplate;
+    ///
+    /// let template = UriTemplate::parse("db://users/{id}/posts/{post}").unwrap();
+    /// let variables = template.match_uri("db://users/42/posts/hello%20world").unwrap();
+    /// assert_eq!(variables["id"], "42");
+    /// assert_eq!(variables["post"], "hello world");
+    /// assert!(template.match_uri("db://users/42").is_none());
+    /// ```
+    pub fn match_uri(&self, uri: &str) -> Option<HashMap<String, String>> {
+        let mut variables = HashMap::new();
+        let mut rest = uri;
+        let mut parts = self.parts.iter().peekable();
+        while let Some(part) = parts.next() {
+            match part {
+                Part::Literal(literal) => rest = rest.strip_prefix(literal.as_str())?,
+                Part::Variable(name) => {
+                    // A variable runs to the end of its segment; the following
+                    // literal (if any) must start within or right after it
+                    let segment_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+                    let end = match parts.peek() {
+                        Some(Part::Literal(next)) => (0..=segment_end).rev().find(|&i| {
+                            rest.get(i..)
+                                .is_some_and(|tail| tail.starts_with(next.as_str()))
+                        })?,
+                        _ => segment_end,
+                    };
+                    if end == 0 || end > segment_end {
+                        return None;
+                    }
+                    variables.insert(name.clone(), percent_decode(&rest[..end])?);
+                    rest = &rest[end..];
+                }
+            }
+        }
+        rest.is_empty().then_some(variables)
+    }
+
+    /// Expand the template with the given variables, percent-encoding values.
+    ///
+    /// Missing variables expand to the empty string, as in RFC 6570.
+    pub fn expand(&self, variables: &HashMap<String, String>) -> String {
+        let mut uri = String::with_capacity(self.template.len());
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => uri.push_str(literal),
+                Part::Variable(name) => {
+                    if let Some(value) = variables.get(name) {
+                        percent_encode_into(value, &mut uri);
+                    }
+                }
+            }
+        }
+        uri
+    }
+
+    /// Number of literal characters, used to prefer the most specific match.
+    pub(crate) fn literal_len(&self) -> usize {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Literal(literal) => literal.len(),
+                Part::Variable(_) => 0,
+            })
+            .sum()
+    }
+}
+
+impl fmt::Display for UriTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+fn validate_variable_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("empty variable name".to_string());
+    }
+    if let Some(op) = name.chars().next().filter(|c| "+#./;?&=,!@|".contains(*c)) {
+        return Err(format!(
+            "operator '{}' is not supported (only level-1 templates are)",
+            op
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+    {
+        return Err(format!("invalid variable name '{}'", name));
+    }
+    Ok(())
+}
+
+/// Characters left unencoded by simple string expansion (RFC 3986 "unreserved").
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+fn percent_encode_into(value: &str, out: &mut String) {
+    for byte in value.bytes() {
+        if is_unreserved(byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_invalid_templates() {
+        assert!(UriTemplate::parse("db://users/{id}").is_ok());
+        assert!(UriTemplate::parse("db://users/{id").is_err());
+        assert!(UriTemplate::parse("db://users/id}").is_err());
+        assert!(UriTemplate::parse("db://users/{}").is_err());
+        assert!(UriTemplate::parse("db://users/{+id}").is_err());
+        assert!(UriTemplate::parse("db://{a}{b}").is_err());
+        assert!(UriTemplate::parse("db://{a}/{a}").is_err());
+    }
+
+    #[test]
+    fn test_match_uri() {
+        let template = UriTemplate::parse("db://users/{id}").unwrap();
+        assert_eq!(template.match_uri("db://users/7").unwrap()["id"], "7");
+        assert!(template.match_uri("db://users/").is_none());
+        assert!(template.match_uri("db://users/7/posts").is_none());
+        assert!(template.match_uri("db://groups/7").is_none());
+
+        let template = UriTemplate::parse("logs://{date}.log").unwrap();
+        assert_eq!(
+            template.match_uri("logs://2025.01.02.log").unwrap()["date"],
+            "2025.01.02"
+        );
+        assert!(template.match_uri("logs://a/b.log").is_none());
+
+        // Non-ASCII input must not split a character while searching
+        let template = UriTemplate::parse("x://{id}.json").unwrap();
+        assert!(template.match_uri("x://ab/€€").is_none());
+        assert!(template.match_uri("x://€").is_none());
+        assert_eq!(template.match_uri("x://€.json").unwrap()["id"], "€");
+    }
+
+    #[test]
+    fn test_expand_round_trips() {
+        let template = UriTemplate::parse("search://{query}/page").unwrap();
+        let variables = HashMap::from([("query".to_string(), "a b/c".to_string())]);
+        let uri = template.expand(&variables);
+        assert_eq!(uri, "search://a%20b%2Fc/page");
+        assert_eq!(template.match_uri(&uri).unwrap(), variables);
+    }
+
+    #[test]
+    fn test_invalid_percent_encoding_does_not_match() {
+        let template = UriTemplate::parse("db://users/{id}").unwrap();
+        assert!(template.match_uri("db://users/%zz").is_none());
+        assert!(template.match_uri("db://users/%ff").is_none());
+    }
+}
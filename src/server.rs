@@ -1,21 +1,38 @@
 //! MCP server implementation.
 
+use crate::auth::{self, AccessPolicy, Authenticator};
 use crate::config::ServerConfig;
+use crate::content::ToolResult;
+use crate::context::{CancellationToken, HttpParts, RequestContext, REQUEST_ID_HEADER};
 use crate::error::{HttpError, McpError};
+use crate::fn_handlers::{FnPrompt, FnResource, FnTool};
+use crate::lifecycle::{
+    LoggingCapability, PromptsCapability, ResourcesCapability, ServerCapabilities, ServerInfo,
+    ToolsCapability,
+};
+use crate::pagination::paginate;
 use crate::prompt::Prompt;
-use crate::resource::Resource;
+use crate::registry::{self, Registry, ServerHandle, SharedRegistry};
+use crate::resource::{Resource, ResourceNotifier};
+use crate::resource_template::ResourceTemplate;
+use crate::session::SessionManager;
 use crate::tool::Tool;
+use crate::tool_error::ToolError;
+use crate::transport::{stdio, streamable_http};
 use crate::validation::{validate_prompt_name, validate_resource_uri, validate_tool_name};
-use axum::http::{HeaderName, HeaderValue};
+use axum::http::{Extensions, HeaderMap, HeaderName, HeaderValue};
 use axum::{
-    extract::State,
+    extract::{Query, State},
+    middleware,
     response::Json,
     routing::{get, post},
     Router,
 };
+use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::{
@@ -27,32 +44,40 @@ use tower_http::{
 use uuid::Uuid;
 
 /// An MCP server that handles tools, resources, and prompts.
-#[derive(Clone)]
+///
+/// Cloning a server copies its registries, so registrations on the clone do not
+/// affect the original. Both still share the same sessions.
 pub struct McpServer {
-    tools: HashMap<String, Arc<dyn Tool>>,
-    resources: HashMap<String, Arc<dyn Resource>>,
-    prompts: HashMap<String, Arc<dyn Prompt>>,
+    registry: SharedRegistry,
     config: ServerConfig,
+    info: ServerInfo,
+    state: Arc<Extensions>,
+    authenticator: Option<Arc<dyn Authenticator>>,
+    pub(crate) sessions: Arc<SessionManager>,
 }
 
 impl McpServer {
     /// Create a new MCP server with default configuration.
     pub fn new() -> Self {
         Self {
-            tools: HashMap::new(),
-            resources: HashMap::new(),
-            prompts: HashMap::new(),
+            registry: SharedRegistry::default(),
             config: ServerConfig::default(),
+            info: ServerInfo::default(),
+            state: Arc::new(Extensions::new()),
+            authenticator: None,
+            sessions: Arc::new(SessionManager::new()),
         }
     }
 
     /// Create a new MCP server with custom configuration.
     pub fn with_config(config: ServerConfig) -> Self {
         Self {
-            tools: HashMap::new(),
-            resources: HashMap::new(),
-            prompts: HashMap::new(),
+            registry: SharedRegistry::default(),
             config,
+            info: ServerInfo::default(),
+            state: Arc::new(Extensions::new()),
+            authenticator: None,
+            sessions: Arc::new(SessionManager::new()),
         }
     }
 
@@ -66,6 +91,133 @@ impl McpServer {
         &mut self.config
     }
 
+    /// Set the server identity reported during initialization (builder style).
+    pub fn with_server_info(mut self, info: ServerInfo) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Get the server identity reported during initialization.
+    pub fn server_info(&self) -> &ServerInfo {
+        &self.info
+    }
+
+    /// Get a mutable reference to the server identity.
+    pub fn server_info_mut(&mut self) -> &mut ServerInfo {
+        &mut self.info
+    }
+
+    /// Share a value of type `S` with every tool, resource, and prompt (builder style).
+    ///
+    /// Handlers retrieve it by type with [`RequestContext::state`], much like
+    /// axum's `State` extractor. Call this once per type, e.g. for a database
+    /// pool and an HTTP client; setting a second value of the same type
+    /// replaces the first. Wrap values that are expensive to clone in an `Arc`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use axum_mcp::McpServer;
+    ///
+    /// #[derive(Clone)]
+    /// struct DbPool;
+    ///
+    /// let server = McpServer::new().with_state(DbPool);
+    /// // In a handler: let pool = ctx.state::<DbPool>().ok_or("no database")?;
+    /// ```
+    pub fn with_state<S>(mut self, state: S) -> Self
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.state).insert(state);
+        self
+    }
+
+    /// Require every HTTP request to authenticate (builder style).
+    ///
+    /// All routes except `/health` run `authenticator` first. Requests it
+    /// rejects get `401 Unauthorized` with a `WWW-Authenticate` challenge; the
+    /// [`Principal`](crate::Principal) of accepted requests is available through
+    /// [`RequestContext::principal`]. See [`auth`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use axum_mcp::{auth::ApiKeyAuthenticator, McpServer};
+    ///
+    /// let server = McpServer::new()
+    ///     .with_authenticator(ApiKeyAuthenticator::new().with_key("ci-bot", "s3cr3t"));
+    /// ```
+    pub fn with_authenticator(mut self, authenticator: impl Authenticator + 'static) -> Self {
+        self.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Create the context for a request, carrying the server's shared state.
+    pub(crate) fn request_context(&self) -> RequestContext {
+        RequestContext::new().with_state(Arc::clone(&self.state))
+    }
+
+    /// Get a handle to the registry of connected sessions.
+    ///
+    /// The handle stays valid after the server is turned into a router, so it can
+    /// be used to send notifications or requests to clients from elsewhere.
+    pub fn session_manager(&self) -> Arc<SessionManager> {
+        Arc::clone(&self.sessions)
+    }
+
+    /// Get a handle for announcing resource changes to subscribed clients.
+    pub fn resource_notifier(&self) -> ResourceNotifier {
+        ResourceNotifier::new(self.session_manager())
+    }
+
+    /// Get a handle for adding and removing tools, resources, and prompts at runtime.
+    ///
+    /// Changes made through the handle apply to this server (and the router it
+    /// becomes) and are announced to connected clients with `list_changed`
+    /// notifications. Once a handle exists, all three capabilities are
+    /// advertised with `listChanged`, even while their lists are empty.
+    pub fn handle(&self) -> ServerHandle {
+        ServerHandle::new(Arc::clone(&self.registry), self.session_manager())
+    }
+
+    /// Compute the capabilities advertised to clients.
+    ///
+    /// A capability is only advertised if at least one handler of that kind is
+    /// registered, or if a [`ServerHandle`] may register one later.
+    pub fn capabilities(&self) -> ServerCapabilities {
+        let registry = self.registry();
+        let dynamic = registry.dynamic;
+        ServerCapabilities {
+            tools: (dynamic || !registry.tools.is_empty()).then_some(ToolsCapability {
+                list_changed: dynamic,
+            }),
+            resources: (dynamic
+                || !registry.resources.is_empty()
+                || !registry.resource_templates.is_empty())
+            .then(|| ResourcesCapability {
+                subscribe: registry.resources.values().any(|r| r.subscribable())
+                    || registry
+                        .resource_templates
+                        .iter()
+                        .any(|t| t.handler.subscribable()),
+                list_changed: dynamic,
+            }),
+            prompts: (dynamic || !registry.prompts.is_empty()).then_some(PromptsCapability {
+                list_changed: dynamic,
+            }),
+            logging: Some(LoggingCapability {}),
+        }
+    }
+
+    /// Lock the registry for reading.
+    ///
+    /// Callers clone what they need out of it rather than holding the guard
+    /// across an `.await`.
+    pub(crate) fn registry(&self) -> RwLockReadGuard<'_, Registry> {
+        registry::read(&self.registry)
+    }
+
     /// Register a tool.
     ///
     /// Validates the tool name according to MCP specification before registration.
@@ -78,11 +230,24 @@ impl McpServer {
         name: impl Into<String>,
         tool: impl Tool + 'static,
     ) -> Result<(), McpError> {
-        let name = name.into();
-        validate_tool_name(&name)
-            .map_err(|e| McpError::Validation(format!("Invalid tool name '{}': {}", name, e)))?;
-        self.tools.insert(name, Arc::new(tool));
-        Ok(())
+        registry::write(&self.registry).insert_tool(name.into(), Arc::new(tool), None)
+    }
+
+    /// Register a tool that only callers allowed by `policy` can use.
+    ///
+    /// Other callers do not see the tool in `tools/list`, and calling it
+    /// fails with `403 Forbidden`. See [`AccessPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    pub fn register_tool_with_policy(
+        &mut self,
+        name: impl Into<String>,
+        tool: impl Tool + 'static,
+        policy: AccessPolicy,
+    ) -> Result<(), McpError> {
+        registry::write(&self.registry).insert_tool(name.into(), Arc::new(tool), Some(policy))
     }
 
     /// Register a resource.
@@ -97,11 +262,25 @@ impl McpServer {
         name: impl Into<String>,
         resource: impl Resource + 'static,
     ) -> Result<(), McpError> {
-        let name = name.into();
-        validate_resource_uri(&name)
-            .map_err(|e| McpError::Validation(format!("Invalid resource URI '{}': {}", name, e)))?;
-        self.resources.insert(name, Arc::new(resource));
-        Ok(())
+        registry::write(&self.registry).insert_resource(name.into(), Arc::new(resource))
+    }
+
+    /// Register a resource template.
+    ///
+    /// Reads of URIs that match no registered resource fall back to the
+    /// templates; when several match, the one with the most literal characters
+    /// wins. Registering the same template string again replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the template is not a valid level-1 URI
+    /// template with a scheme.
+    pub fn register_resource_template(
+        &mut self,
+        template: impl Into<String>,
+        handler: impl ResourceTemplate + 'static,
+    ) -> Result<(), McpError> {
+        registry::write(&self.registry).insert_resource_template(template.into(), Arc::new(handler))
     }
 
     /// Register a prompt.
@@ -116,11 +295,50 @@ impl McpServer {
         name: impl Into<String>,
         prompt: impl Prompt + 'static,
     ) -> Result<(), McpError> {
-        let name = name.into();
-        validate_prompt_name(&name)
-            .map_err(|e| McpError::Validation(format!("Invalid prompt name '{}': {}", name, e)))?;
-        self.prompts.insert(name, Arc::new(prompt));
-        Ok(())
+        registry::write(&self.registry).insert_prompt(name.into(), Arc::new(prompt), None)
+    }
+
+    /// Register a prompt that only callers allowed by `policy` can use.
+    ///
+    /// Other callers do not see the prompt in `prompts/list`, and getting it
+    /// fails with `403 Forbidden`. See [`AccessPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    pub fn register_prompt_with_policy(
+        &mut self,
+        name: impl Into<String>,
+        prompt: impl Prompt + 'static,
+        policy: AccessPolicy,
+    ) -> Result<(), McpError> {
+        registry::write(&self.registry).insert_prompt(name.into(), Arc::new(prompt), Some(policy))
+    }
+
+    /// Restrict the resources whose URI starts with `prefix` to callers
+    /// allowed by `policy` (builder style).
+    ///
+    /// Applies to resources and resource templates registered before or
+    /// after. Other callers do not see them in `resources/list` or
+    /// `resources/templates/list`, and reading or subscribing to them fails
+    /// with `403 Forbidden`. When several prefixes match, every policy must
+    /// allow the caller. A URI read through a template is checked in
+    /// canonical form (the template expanded with the decoded variables), so
+    /// percent-encoding it does not get around the prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use axum_mcp::{auth::AccessPolicy, McpServer};
+    ///
+    /// let server = McpServer::new()
+    ///     .with_resource_policy("db://admin/", AccessPolicy::require_scopes(["admin"]));
+    /// ```
+    pub fn with_resource_policy(self, prefix: impl Into<String>, policy: AccessPolicy) -> Self {
+        registry::write(&self.registry)
+            .resource_policies
+            .push((prefix.into(), policy));
+        self
     }
 
     /// Register a tool using builder pattern (chainable).
@@ -159,6 +377,46 @@ impl McpServer {
         Ok(self)
     }
 
+    /// Register a tool restricted by `policy` using builder pattern (chainable).
+    ///
+    /// See [`register_tool_with_policy`](Self::register_tool_with_policy).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::{auth::AccessPolicy, McpServer, Tool};
+    /// # use async_trait::async_trait;
+    /// # use serde_json::Value;
+    /// # struct DeleteUserTool;
+    /// # #[async_trait]
+    /// # impl Tool for DeleteUserTool {
+    /// #     fn description(&self) -> &str { "delete a user" }
+    /// #     fn schema(&self) -> Value { Value::Null }
+    /// #     async fn call(&self, _: &Value) -> Result<Value, String> {
+    /// #         Ok(Value::Null)
+    /// #     }
+    /// # }
+    /// let server = McpServer::new().tool_with_policy(
+    ///     "delete_user",
+    ///     DeleteUserTool,
+    ///     AccessPolicy::require_scopes(["users:write"]),
+    /// )?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn tool_with_policy(
+        mut self,
+        name: impl Into<String>,
+        tool: impl Tool + 'static,
+        policy: AccessPolicy,
+    ) -> Result<Self, McpError> {
+        self.register_tool_with_policy(name, tool, policy)?;
+        Ok(self)
+    }
+
     /// Register a resource using builder pattern (chainable).
     ///
     /// This method allows chaining multiple registrations together.
@@ -193,6 +451,41 @@ impl McpServer {
         Ok(self)
     }
 
+    /// Register a resource template using builder pattern (chainable).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the template is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::{McpServer, ResourceTemplate};
+    /// # use async_trait::async_trait;
+    /// # use std::collections::HashMap;
+    /// # struct UserResource;
+    /// # #[async_trait]
+    /// # impl ResourceTemplate for UserResource {
+    /// #     fn name(&self) -> &str { "user" }
+    /// #     fn description(&self) -> &str { "user" }
+    /// #     fn mime_type(&self) -> &str { "application/json" }
+    /// #     async fn read(&self, _: &HashMap<String, String>) -> Result<String, String> {
+    /// #         Ok("{}".to_string())
+    /// #     }
+    /// # }
+    /// let server = McpServer::new()
+    ///     .resource_template("db://users/{id}", UserResource)?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn resource_template(
+        mut self,
+        template: impl Into<String>,
+        handler: impl ResourceTemplate + 'static,
+    ) -> Result<Self, McpError> {
+        self.register_resource_template(template, handler)?;
+        Ok(self)
+    }
+
     /// Register a prompt using builder pattern (chainable).
     ///
     /// This method allows chaining multiple registrations together.
@@ -229,23 +522,296 @@ impl McpServer {
         Ok(self)
     }
 
+    /// Register a prompt restricted by `policy` using builder pattern (chainable).
+    ///
+    /// See [`register_prompt_with_policy`](Self::register_prompt_with_policy).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    pub fn prompt_with_policy(
+        mut self,
+        name: impl Into<String>,
+        prompt: impl Prompt + 'static,
+        policy: AccessPolicy,
+    ) -> Result<Self, McpError> {
+        self.register_prompt_with_policy(name, prompt, policy)?;
+        Ok(self)
+    }
+
+    /// Register a tool backed by an async closure (chainable).
+    ///
+    /// The closure receives the call's arguments and returns the result
+    /// value. See [`fn_handlers`](crate::fn_handlers) for capturing shared state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::McpServer;
+    /// use serde_json::{json, Value};
+    ///
+    /// let server = McpServer::new().tool_fn(
+    ///     "add",
+    ///     "Add two numbers",
+    ///     json!({
+    ///         "type": "object",
+    ///         "properties": { "a": { "type": "number" }, "b": { "type": "number" } },
+    ///         "required": ["a", "b"]
+    ///     }),
+    ///     |args: Value| async move {
+    ///         let sum = args["a"].as_f64().unwrap_or(0.0) + args["b"].as_f64().unwrap_or(0.0);
+    ///         Ok(json!(sum))
+    ///     },
+    /// )?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn tool_fn<F, Fut>(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        schema: Value,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Value, ToolError>> + Send + 'static,
+    {
+        self.tool(name, FnTool::new(description, schema, handler))
+    }
+
+    /// Register a resource backed by an async closure (chainable).
+    ///
+    /// The closure returns the resource's text content.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the resource URI is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::McpServer;
+    /// let server = McpServer::new().resource_fn(
+    ///     "config://version",
+    ///     "Version",
+    ///     "Server version",
+    ///     "text/plain",
+    ///     || async { Ok(env!("CARGO_PKG_VERSION").to_string()) },
+    /// )?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn resource_fn<F, Fut>(
+        self,
+        uri: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        mime_type: impl Into<String>,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        self.resource(uri, FnResource::new(name, description, mime_type, handler))
+    }
+
+    /// Register a prompt backed by an async closure (chainable).
+    ///
+    /// The closure receives the prompt's arguments and returns the rendered text.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::McpServer;
+    /// use serde_json::{json, Value};
+    ///
+    /// let server = McpServer::new().prompt_fn(
+    ///     "greeting",
+    ///     "Greet someone",
+    ///     json!([{ "name": "name", "required": true }]),
+    ///     |args: Value| async move {
+    ///         Ok(format!("Hello, {}!", args["name"].as_str().unwrap_or("World")))
+    ///     },
+    /// )?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn prompt_fn<F, Fut>(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        arguments: Value,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        self.prompt(name, FnPrompt::new(description, arguments, handler))
+    }
+
+    /// Register a tool backed by an async closure that also receives the
+    /// request context (chainable).
+    ///
+    /// Like [`tool_fn`](Self::tool_fn), but the closure gets a clone of the
+    /// [`RequestContext`], e.g. to see the caller or report progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the tool name is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use axum_mcp::{McpServer, RequestContext};
+    /// use serde_json::{json, Value};
+    ///
+    /// let server = McpServer::new().tool_fn_with_context(
+    ///     "index",
+    ///     "Index all documents",
+    ///     json!({ "type": "object" }),
+    ///     |_args: Value, ctx: RequestContext| async move {
+    ///         for i in 0..10 {
+    ///             ctx.report_progress(i as f64 + 1.0, Some(10.0), None);
+    ///         }
+    ///         Ok(json!({ "indexed": 10 }))
+    ///     },
+    /// )?;
+    /// # Ok::<(), axum_mcp::McpError>(())
+    /// ```
+    pub fn tool_fn_with_context<F, Fut>(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        schema: Value,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn(Value, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Value, ToolError>> + Send + 'static,
+    {
+        self.tool(name, FnTool::with_context(description, schema, handler))
+    }
+
+    /// Register a resource backed by an async closure that also receives the
+    /// request context (chainable).
+    ///
+    /// Like [`resource_fn`](Self::resource_fn), but the closure gets a clone
+    /// of the [`RequestContext`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the resource URI is invalid.
+    pub fn resource_fn_with_context<F, Fut>(
+        self,
+        uri: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        mime_type: impl Into<String>,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn(RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        self.resource(
+            uri,
+            FnResource::with_context(name, description, mime_type, handler),
+        )
+    }
+
+    /// Register a prompt backed by an async closure that also receives the
+    /// request context (chainable).
+    ///
+    /// Like [`prompt_fn`](Self::prompt_fn), but the closure gets a clone of
+    /// the [`RequestContext`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McpError::Validation` if the prompt name is invalid.
+    pub fn prompt_fn_with_context<F, Fut>(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        arguments: Value,
+        handler: F,
+    ) -> Result<Self, McpError>
+    where
+        F: Fn(Value, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        self.prompt(
+            name,
+            FnPrompt::with_context(description, arguments, handler),
+        )
+    }
+
     /// Build the Axum router.
     ///
+    /// Exposes the REST routes and the Streamable HTTP MCP endpoint at
+    /// [`ServerConfig::mcp_path`] (default `/mcp`). See
+    /// [`transport::streamable_http`](crate::transport::streamable_http).
+    /// With the `websocket` feature, a WebSocket endpoint is also served at
+    /// `{mcp_path}/ws`.
+    ///
     /// Includes middleware for:
     /// - Request tracing and logging
     /// - Request ID generation
     /// - CORS support
     /// - Request body size limits (10MB default)
+    /// - Authentication of every route but `/health`, if an
+    ///   [`Authenticator`] was set with [`with_authenticator`](Self::with_authenticator);
+    ///   its protected resource metadata, if any, is served publicly at
+    ///   `/.well-known/oauth-protected-resource`
     pub fn router(self) -> Router {
         let state = Arc::new(self);
-        Router::new()
-            .route("/health", get(health))
+        let router = Router::new()
             .route("/tools/list", get(list_tools))
             .route("/tools/call", post(call_tool))
             .route("/resources/list", get(list_resources))
             .route("/resources/read", post(read_resource))
+            .route("/resources/templates/list", get(list_resource_templates))
             .route("/prompts/list", get(list_prompts))
             .route("/prompts/get", post(get_prompt))
+            .route(
+                &state.config.mcp_path,
+                post(streamable_http::post_handler)
+                    .get(streamable_http::get_handler)
+                    .delete(streamable_http::delete_handler),
+            );
+        #[cfg(feature = "websocket")]
+        let router = router.route(
+            &format!("{}/ws", state.config.mcp_path.trim_end_matches('/')),
+            get(crate::transport::websocket::ws_handler),
+        );
+        // Only the routes above require authentication
+        let router = match &state.authenticator {
+            Some(authenticator) => router.route_layer(middleware::from_fn_with_state(
+                Arc::clone(authenticator),
+                auth::require_auth,
+            )),
+            None => router,
+        };
+        let metadata = state
+            .authenticator
+            .as_ref()
+            .and_then(|authenticator| authenticator.resource_metadata());
+        let router = match metadata {
+            Some(metadata) => router.route(
+                auth::PROTECTED_RESOURCE_METADATA_PATH,
+                get(move || async move { Json(metadata) }),
+            ),
+            None => router,
+        };
+        router
+            .route("/health", get(health))
             .layer(
                 ServiceBuilder::new()
                     .layer(
@@ -253,7 +819,7 @@ impl McpServer {
                             .make_span_with(|request: &axum::http::Request<_>| {
                                 let request_id = request
                                     .headers()
-                                    .get("x-request-id")
+                                    .get(REQUEST_ID_HEADER)
                                     .and_then(|v| v.to_str().ok())
                                     .unwrap_or("unknown");
                                 tracing::info_span!(
@@ -284,7 +850,7 @@ impl McpServer {
                             ),
                     )
                     .layer(SetRequestIdLayer::new(
-                        HeaderName::from_static("x-request-id"),
+                        HeaderName::from_static(REQUEST_ID_HEADER),
                         UuidRequestId,
                     ))
                     .layer(RequestBodyLimitLayer::new(state.config.max_body_size))
@@ -332,6 +898,49 @@ impl McpServer {
         tracing::info!("MCP server shutting down gracefully");
         Ok(())
     }
+
+    /// Serve over stdin/stdout as a local subprocess MCP server.
+    ///
+    /// Messages are newline-delimited JSON-RPC. The same registries, timeouts,
+    /// and schema validation apply as over HTTP. Returns when stdin is closed.
+    ///
+    /// stdout is reserved for protocol messages. If no tracing subscriber is
+    /// installed yet, one is installed that writes to stderr (filtered by
+    /// `RUST_LOG`). If you install your own, make sure it writes to stderr.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use axum_mcp::McpServer;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let server = McpServer::new();
+    ///     server.serve_stdio().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn serve_stdio(self) -> Result<(), McpError> {
+        let _ = tracing_subscriber::fmt()
+            .with_writer(std::io::stderr)
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+            .try_init();
+        tracing::info!("MCP server listening on stdio");
+        stdio::serve_io(self, tokio::io::stdin(), tokio::io::stdout()).await
+    }
+}
+
+impl Clone for McpServer {
+    fn clone(&self) -> Self {
+        Self {
+            registry: Arc::new(RwLock::new(self.registry().clone())),
+            config: self.config.clone(),
+            info: self.info.clone(),
+            state: Arc::clone(&self.state),
+            authenticator: self.authenticator.clone(),
+            sessions: Arc::clone(&self.sessions),
+        }
+    }
 }
 
 impl Default for McpServer {
@@ -341,69 +950,150 @@ impl Default for McpServer {
 }
 
 async fn health(State(server): State<Arc<McpServer>>) -> Json<Value> {
-    let tool_count = server.tools.len();
-    let resource_count = server.resources.len();
-    let prompt_count = server.prompts.len();
+    let registry = server.registry();
+    let tool_count = registry.tools.len();
+    let resource_count = registry.resources.len();
+    let resource_template_count = registry.resource_templates.len();
+    let prompt_count = registry.prompts.len();
+    drop(registry);
+    let session_count = server.sessions.len();
 
     Json(serde_json::json!({
         "status": "ok",
         "version": env!("CARGO_PKG_VERSION"),
         "tools": tool_count,
         "resources": resource_count,
+        "resource_templates": resource_template_count,
         "prompts": prompt_count,
+        "sessions": session_count,
     }))
 }
 
-async fn list_tools(State(server): State<Arc<McpServer>>) -> Json<Value> {
-    let tools: Vec<Value> = server
-        .tools
-        .iter()
-        .map(|(name, tool)| {
-            let description = tool.description().to_string();
-            let schema = tool.schema();
-            serde_json::json!({
-                "name": name,
-                "description": description,
-                "inputSchema": schema,
-            })
-        })
-        .collect();
-    Json(serde_json::json!({ "tools": tools }))
+async fn list_tools(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server
+        .handle_list_tools(query.cursor.as_deref(), &ctx)
+        .map(Json)
 }
 
 async fn call_tool(
     State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
     Json(payload): Json<Value>,
 ) -> Result<Json<Value>, HttpError> {
-    let name = payload
-        .get("name")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| HttpError::bad_request("Missing 'name' field in request".to_string()))?;
-
-    // Validate tool name format
-    validate_tool_name(name)
-        .map_err(|e| HttpError::bad_request(format!("Invalid tool name: {}", e)))?;
-
-    let arguments = payload
-        .get("arguments")
-        .cloned()
-        .unwrap_or_else(|| serde_json::json!({}));
-
-    let tool = server
-        .tools
-        .get(name)
-        .ok_or_else(|| HttpError::not_found(format!("Tool '{}' not found", name)))?;
-
-    // Validate arguments against tool schema
-    let schema = tool.schema();
-    let compiled = jsonschema::JSONSchema::compile(&schema).map_err(|e| {
-        tracing::warn!("Failed to compile tool schema for '{}': {}", name, e);
-        HttpError::internal("Invalid tool schema configuration".to_string())
-    })?;
+    // REST responses cannot stream, so only cancellation is wired up: the
+    // guard cancels the token if the client disconnects mid-call
+    let token = CancellationToken::new();
+    let guard = token.clone().drop_guard();
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions))
+        .with_cancellation(token);
+    let result = server.handle_call_tool(&payload, &ctx).await;
+    guard.disarm();
+    let result = result?;
+
+    // Legacy REST clients expect tool failures as HTTP errors
+    if result.is_error && !server.config.tool_errors_as_results {
+        return Err(HttpError::internal(format!(
+            "Tool execution failed: {}",
+            result.text_content()
+        ))
+        .with_error_type(ToolError::EXECUTION_FAILED));
+    }
+    tool_result_json(result).map(Json)
+}
+
+async fn list_resources(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server
+        .handle_list_resources(query.cursor.as_deref(), &ctx)
+        .map(Json)
+}
+
+async fn list_resource_templates(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server
+        .handle_list_resource_templates(query.cursor.as_deref(), &ctx)
+        .map(Json)
+}
+
+async fn read_resource(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Json(payload): Json<Value>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server.handle_read_resource(&payload, &ctx).await.map(Json)
+}
+
+async fn list_prompts(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server
+        .handle_list_prompts(query.cursor.as_deref(), &ctx)
+        .map(Json)
+}
+
+async fn get_prompt(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Json(payload): Json<Value>,
+) -> Result<Json<Value>, HttpError> {
+    let ctx = server
+        .request_context()
+        .with_http(HttpParts::new(headers, extensions));
+    server.handle_get_prompt(&payload, &ctx).await.map(Json)
+}
+
+/// Serialize a tool result for the wire.
+pub(crate) fn tool_result_json(result: ToolResult) -> Result<Value, HttpError> {
+    serde_json::to_value(result).map_err(|e| {
+        tracing::error!("Failed to serialize tool result: {}", e);
+        HttpError::internal("Failed to serialize tool result".to_string())
+    })
+}
 
-    let validation_result = compiled.validate(&arguments);
-    if let Err(errors) = validation_result {
-        let error_messages: Vec<String> = errors
+/// Validate `instance` against `schema`, returning one message per violation.
+///
+/// Fails if the schema itself does not compile.
+fn schema_errors(schema: &Value, instance: &Value) -> Result<Vec<String>, String> {
+    let compiled = jsonschema::JSONSchema::compile(schema).map_err(|e| e.to_string())?;
+    let messages = match compiled.validate(instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
             .map(|e| {
                 let path = if e.instance_path.to_string().is_empty() {
                     "root".to_string()
@@ -412,138 +1102,61 @@ async fn call_tool(
                 };
                 format!("{}: {}", path, e)
             })
-            .collect();
-        tracing::debug!(
-            "Schema validation failed for tool '{}' with arguments {:?}: {:?}",
+            .collect(),
+    };
+    Ok(messages)
+}
+
+/// Check a tool's structured result against its declared output schema.
+fn check_output(name: &str, output_schema: &Value, result: &ToolResult) -> Result<(), HttpError> {
+    let structured = result.structured_content.as_ref().ok_or_else(|| {
+        tracing::error!(
+            "Tool '{}' declares an output schema but returned no structured content",
+            name
+        );
+        HttpError::internal(format!(
+            "Tool '{}' returned no structured content for its output schema",
+            name
+        ))
+    })?;
+    let error_messages = schema_errors(output_schema, structured).map_err(|e| {
+        tracing::warn!("Failed to compile output schema for '{}': {}", name, e);
+        HttpError::internal("Invalid tool output schema configuration".to_string())
+    })?;
+    if !error_messages.is_empty() {
+        tracing::error!(
+            "Output of tool '{}' failed schema validation: {:?}",
             name,
-            arguments,
             error_messages
         );
-        return Err(HttpError::bad_request(format!(
-            "Arguments for tool '{}' failed schema validation: {}",
+        return Err(HttpError::internal(format!(
+            "Output of tool '{}' failed schema validation: {}",
             name,
             error_messages.join(", ")
         )));
     }
-
-    // Execute tool with configured timeout
-    let timeout_duration = server.config.tool_timeout;
-    let result = tokio::time::timeout(timeout_duration, tool.call(&arguments)).await;
-
-    match result {
-        Ok(Ok(result_value)) => {
-            let text = serde_json::to_string(&result_value).map_err(|e| {
-                tracing::error!("Failed to serialize tool result: {}", e);
-                HttpError::internal("Failed to serialize tool result".to_string())
-            })?;
-            Ok(Json(serde_json::json!({
-                "content": [{
-                    "type": "text",
-                    "text": text
-                }]
-            })))
-        }
-        Ok(Err(e)) => {
-            tracing::error!("Tool execution error: {}", e);
-            Err(HttpError::internal(format!("Tool execution failed: {}", e)))
-        }
-        Err(_) => {
-            tracing::warn!(
-                "Tool '{}' execution timed out after {:?}",
-                name,
-                timeout_duration
-            );
-            Err(HttpError::internal(format!(
-                "Tool '{}' execution timed out after {:?}",
-                name, timeout_duration
-            )))
-        }
-    }
+    Ok(())
 }
 
-async fn list_resources(State(server): State<Arc<McpServer>>) -> Json<Value> {
-    let resources: Vec<Value> = server
-        .resources
-        .iter()
-        .map(|(name, resource)| {
-            let resource_name = resource.name().to_string();
-            let description = resource.description().to_string();
-            let mime_type = resource.mime_type().to_string();
-            serde_json::json!({
-                "uri": name,
-                "name": resource_name,
-                "description": description,
-                "mimeType": mime_type,
-            })
-        })
-        .collect();
-    Json(serde_json::json!({ "resources": resources }))
+/// Query parameters of the REST list routes.
+#[derive(Deserialize)]
+struct ListQuery {
+    cursor: Option<String>,
 }
 
-async fn read_resource(
-    State(server): State<Arc<McpServer>>,
-    Json(payload): Json<Value>,
-) -> Result<Json<Value>, HttpError> {
-    let uri = payload
-        .get("uri")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| HttpError::bad_request("Missing 'uri' field in request".to_string()))?;
-
-    // Validate URI format
-    validate_resource_uri(uri)
-        .map_err(|e| HttpError::bad_request(format!("Invalid resource URI: {}", e)))?;
-
-    let resource = server
-        .resources
-        .get(uri)
-        .ok_or_else(|| HttpError::not_found(format!("Resource '{}' not found", uri)))?;
-
-    // Read resource with configured timeout
-    let timeout_duration = server.config.resource_timeout;
-    let mime_type = resource.mime_type().to_string();
-    let read_result = tokio::time::timeout(timeout_duration, resource.read()).await;
-
-    match read_result {
-        Ok(Ok(content)) => Ok(Json(serde_json::json!({
-            "contents": [{
-                "uri": uri,
-                "mimeType": mime_type,
-                "text": content
-            }]
-        }))),
-        Ok(Err(e)) => {
-            tracing::error!("Resource read error: {}", e);
-            Err(HttpError::internal(format!("Resource read failed: {}", e)))
-        }
-        Err(_) => {
-            tracing::warn!(
-                "Resource '{}' read timed out after {:?}",
-                uri,
-                timeout_duration
-            );
-            Err(HttpError::internal(format!(
-                "Resource '{}' read timed out after {:?}",
-                uri, timeout_duration
-            )))
-        }
+/// Build a list result, adding `nextCursor` when more pages follow.
+fn list_result(field: &str, items: Vec<Value>, next_cursor: Option<String>) -> Value {
+    let mut result = serde_json::json!({ field: items });
+    if let Some(cursor) = next_cursor {
+        result["nextCursor"] = Value::String(cursor);
     }
+    result
 }
 
-async fn list_prompts(State(server): State<Arc<McpServer>>) -> Json<Value> {
-    let prompts: Vec<Value> = server
-        .prompts
-        .iter()
-        .map(|(name, prompt)| {
-            let description = prompt.description().to_string();
-            let arguments = prompt.arguments();
-            serde_json::json!({
-                "name": name,
-                "description": description,
-                "arguments": arguments,
-            })
-        })
-        .collect();
-    Json(serde_json::json!({ "prompts": prompts }))
+/// What a `resources/read` resolved to, detached from the registry lock.
+enum ReadTarget {
+    Resource(Arc<dyn Resource>),
+    Template(Arc<dyn ResourceTemplate>, HashMap<String, String>),
 }
 
 /// Request ID generator using UUID v4.
@@ -557,57 +1170,430 @@ impl MakeRequestId for UuidRequestId {
     }
 }
 
-async fn get_prompt(
-    State(server): State<Arc<McpServer>>,
-    Json(payload): Json<Value>,
-) -> Result<Json<Value>, HttpError> {
-    let name = payload
-        .get("name")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| HttpError::bad_request("Missing 'name' field in request".to_string()))?;
-
-    // Validate prompt name
-    validate_prompt_name(name)
-        .map_err(|e| HttpError::bad_request(format!("Invalid prompt name: {}", e)))?;
-
-    let arguments = payload
-        .get("arguments")
-        .cloned()
-        .unwrap_or_else(|| serde_json::json!({}));
-
-    let prompt = server
-        .prompts
-        .get(name)
-        .ok_or_else(|| HttpError::not_found(format!("Prompt '{}' not found", name)))?;
-
-    // Render prompt with configured timeout
-    let timeout_duration = server.config.prompt_timeout;
-    let render_result = tokio::time::timeout(timeout_duration, prompt.render(&arguments)).await;
-
-    match render_result {
-        Ok(Ok(content)) => Ok(Json(serde_json::json!({
-            "messages": [{
-                "role": "user",
-                "content": {
-                    "type": "text",
-                    "text": content
+/// Request handlers shared by the REST routes and the JSON-RPC endpoint.
+impl McpServer {
+    pub(crate) fn handle_list_tools(
+        &self,
+        cursor: Option<&str>,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let registry = self.registry();
+        let entries = registry
+            .tools
+            .iter()
+            .filter(|(name, _)| registry.tool_allowed(name, ctx))
+            .map(|(name, tool)| (name.clone(), (name.clone(), Arc::clone(tool))))
+            .collect();
+        drop(registry);
+        let page = paginate(entries, cursor, self.config.page_size)?;
+        let tools: Vec<Value> = page
+            .items
+            .iter()
+            .map(|(name, tool)| {
+                let description = tool.description().to_string();
+                let schema = tool.schema();
+                let mut entry = serde_json::json!({
+                    "name": name,
+                    "description": description,
+                    "inputSchema": schema,
+                });
+                if let Some(output_schema) = tool.output_schema() {
+                    entry["outputSchema"] = output_schema;
+                }
+                if let Some(annotations) = tool.annotations() {
+                    entry["annotations"] = serde_json::json!(annotations);
                 }
-            }]
-        }))),
-        Ok(Err(e)) => {
-            tracing::error!("Prompt render error: {}", e);
-            Err(HttpError::internal(format!("Prompt render failed: {}", e)))
+                entry
+            })
+            .collect();
+        Ok(list_result("tools", tools, page.next_cursor))
+    }
+
+    /// Run a tool call.
+    ///
+    /// Failures of the tool itself come back as an `isError` result; the error
+    /// is reserved for protocol problems such as an unknown tool, invalid
+    /// arguments, or a timeout.
+    pub(crate) async fn handle_call_tool(
+        &self,
+        payload: &Value,
+        ctx: &RequestContext,
+    ) -> Result<ToolResult, HttpError> {
+        let name = payload
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| HttpError::bad_request("Missing 'name' field in request".to_string()))?;
+
+        // Validate tool name format
+        validate_tool_name(name)
+            .map_err(|e| HttpError::bad_request(format!("Invalid tool name: {}", e)))?;
+
+        let arguments = payload
+            .get("arguments")
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        let tool = {
+            let registry = self.registry();
+            let tool = registry
+                .tools
+                .get(name)
+                .cloned()
+                .ok_or_else(|| HttpError::not_found(format!("Tool '{}' not found", name)))?;
+            if !registry.tool_allowed(name, ctx) {
+                tracing::info!("Access policy denied call to tool '{}'", name);
+                return Err(HttpError::forbidden(format!(
+                    "Not allowed to call tool '{}'",
+                    name
+                )));
+            }
+            tool
+        };
+
+        if let Some(filter) = &self.config.tool_filter {
+            if !filter.allows(&tool.annotations().unwrap_or_default()) {
+                tracing::info!("Tool filter refused call to tool '{}'", name);
+                return Err(HttpError::forbidden(filter.refusal(name)));
+            }
         }
-        Err(_) => {
-            tracing::warn!(
-                "Prompt '{}' render timed out after {:?}",
+
+        // Validate arguments against tool schema
+        let error_messages = schema_errors(&tool.schema(), &arguments).map_err(|e| {
+            tracing::warn!("Failed to compile tool schema for '{}': {}", name, e);
+            HttpError::internal("Invalid tool schema configuration".to_string())
+        })?;
+        if !error_messages.is_empty() {
+            tracing::debug!(
+                "Schema validation failed for tool '{}' with arguments {:?}: {:?}",
                 name,
-                timeout_duration
+                arguments,
+                error_messages
             );
-            Err(HttpError::internal(format!(
-                "Prompt '{}' render timed out after {:?}",
-                name, timeout_duration
+            return Err(HttpError::bad_request(format!(
+                "Arguments for tool '{}' failed schema validation: {}",
+                name,
+                error_messages.join(", ")
+            )));
+        }
+
+        // Execute tool with configured timeout
+        let timeout_duration = self.config.tool_timeout;
+        let deadline = ctx.start_deadline(timeout_duration);
+        let result = tokio::time::timeout_at(deadline, tool.invoke(&arguments, ctx)).await;
+
+        match result {
+            Ok(Ok(tool_result)) => {
+                // Error results need not conform to the output schema
+                if self.config.validate_output && !tool_result.is_error {
+                    if let Some(output_schema) = tool.output_schema() {
+                        check_output(name, &output_schema, &tool_result)?;
+                    }
+                }
+                Ok(tool_result)
+            }
+            Ok(Err(ToolError::ExecutionFailed(e))) => {
+                tracing::warn!("Tool '{}' execution error: {}", name, e);
+                Ok(ToolResult::error(e))
+            }
+            Ok(Err(e)) => {
+                tracing::debug!("Tool '{}' failed: {}", name, e);
+                Err(e.into())
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Tool '{}' execution timed out after {:?}",
+                    name,
+                    timeout_duration
+                );
+                // Let the tool's cleanup observe that the call was abandoned
+                ctx.cancellation_token().cancel();
+                Err(HttpError {
+                    message: format!(
+                        "Tool '{}' execution timed out after {:?}",
+                        name, timeout_duration
+                    ),
+                    ..ToolError::timeout_after(timeout_duration).into()
+                })
+            }
+        }
+    }
+
+    pub(crate) fn handle_list_resources(
+        &self,
+        cursor: Option<&str>,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let registry = self.registry();
+        let entries = registry
+            .resources
+            .iter()
+            .filter(|(uri, _)| registry.resource_allowed(uri, ctx))
+            .map(|(uri, resource)| (uri.clone(), (uri.clone(), Arc::clone(resource))))
+            .collect();
+        drop(registry);
+        let page = paginate(entries, cursor, self.config.page_size)?;
+        let resources: Vec<Value> = page
+            .items
+            .iter()
+            .map(|(name, resource)| {
+                let resource_name = resource.name().to_string();
+                let description = resource.description().to_string();
+                let mime_type = resource.mime_type().to_string();
+                serde_json::json!({
+                    "uri": name,
+                    "name": resource_name,
+                    "description": description,
+                    "mimeType": mime_type,
+                })
+            })
+            .collect();
+        Ok(list_result("resources", resources, page.next_cursor))
+    }
+
+    pub(crate) fn handle_list_resource_templates(
+        &self,
+        cursor: Option<&str>,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let registry = self.registry();
+        let entries = registry
+            .resource_templates
+            .iter()
+            .filter(|registered| registry.resource_allowed(registered.template.as_str(), ctx))
+            .map(|registered| (registered.template.to_string(), registered.clone()))
+            .collect();
+        drop(registry);
+        let page = paginate(entries, cursor, self.config.page_size)?;
+        let templates: Vec<Value> = page
+            .items
+            .iter()
+            .map(|registered| {
+                serde_json::json!({
+                    "uriTemplate": registered.template.as_str(),
+                    "name": registered.handler.name(),
+                    "description": registered.handler.description(),
+                    "mimeType": registered.handler.mime_type(),
+                })
+            })
+            .collect();
+        Ok(list_result(
+            "resourceTemplates",
+            templates,
+            page.next_cursor,
+        ))
+    }
+
+    /// Check that `uri` names a resource the caller may subscribe to.
+    pub(crate) fn check_subscribable(
+        &self,
+        uri: &str,
+        ctx: &RequestContext,
+    ) -> Result<(), HttpError> {
+        let registry = self.registry();
+        let (subscribable, canonical) = match registry.resources.get(uri) {
+            Some(resource) => (resource.subscribable(), uri.to_string()),
+            None => match registry.match_resource_template(uri) {
+                Some((registered, variables)) => (
+                    registered.handler.subscribable(),
+                    registered.template.expand(&variables),
+                ),
+                None => {
+                    return Err(HttpError::not_found(format!(
+                        "Resource '{}' not found",
+                        uri
+                    )))
+                }
+            },
+        };
+        if !registry.resource_allowed(&canonical, ctx) {
+            return Err(HttpError::forbidden(format!(
+                "Not allowed to subscribe to resource '{}'",
+                uri
+            )));
+        }
+        if subscribable {
+            Ok(())
+        } else {
+            Err(HttpError::bad_request(format!(
+                "Resource '{}' does not support subscriptions",
+                uri
             )))
         }
     }
+
+    pub(crate) async fn handle_read_resource(
+        &self,
+        payload: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let uri = payload
+            .get("uri")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| HttpError::bad_request("Missing 'uri' field in request".to_string()))?;
+
+        // Validate URI format
+        validate_resource_uri(uri)
+            .map_err(|e| HttpError::bad_request(format!("Invalid resource URI: {}", e)))?;
+
+        // Exact URIs take precedence over templates
+        let target = {
+            let registry = self.registry();
+            // Policies see template matches in canonical form, so that
+            // percent-encoding a URI cannot dodge a prefix policy
+            let (target, canonical) = if let Some(resource) = registry.resources.get(uri) {
+                (ReadTarget::Resource(Arc::clone(resource)), uri.to_string())
+            } else if let Some((registered, variables)) = registry.match_resource_template(uri) {
+                let canonical = registered.template.expand(&variables);
+                (
+                    ReadTarget::Template(Arc::clone(&registered.handler), variables),
+                    canonical,
+                )
+            } else {
+                return Err(HttpError::not_found(format!(
+                    "Resource '{}' not found",
+                    uri
+                )));
+            };
+            if !registry.resource_allowed(&canonical, ctx) {
+                tracing::info!("Access policy denied read of resource '{}'", uri);
+                return Err(HttpError::forbidden(format!(
+                    "Not allowed to read resource '{}'",
+                    uri
+                )));
+            }
+            target
+        };
+        let read = async {
+            match &target {
+                ReadTarget::Resource(resource) => resource.read_with_context(uri, ctx).await,
+                ReadTarget::Template(handler, variables) => {
+                    handler.read_with_context(uri, variables, ctx).await
+                }
+            }
+        };
+
+        // Read resource with configured timeout
+        let timeout_duration = self.config.resource_timeout;
+        let deadline = ctx.start_deadline(timeout_duration);
+        let read_result = tokio::time::timeout_at(deadline, read).await;
+
+        match read_result {
+            Ok(Ok(contents)) => Ok(serde_json::json!({ "contents": contents })),
+            Ok(Err(e)) => {
+                tracing::error!("Resource read error: {}", e);
+                Err(HttpError::internal(format!("Resource read failed: {}", e)))
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Resource '{}' read timed out after {:?}",
+                    uri,
+                    timeout_duration
+                );
+                Err(HttpError::internal(format!(
+                    "Resource '{}' read timed out after {:?}",
+                    uri, timeout_duration
+                )))
+            }
+        }
+    }
+
+    pub(crate) fn handle_list_prompts(
+        &self,
+        cursor: Option<&str>,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let registry = self.registry();
+        let entries = registry
+            .prompts
+            .iter()
+            .filter(|(name, _)| registry.prompt_allowed(name, ctx))
+            .map(|(name, prompt)| (name.clone(), (name.clone(), Arc::clone(prompt))))
+            .collect();
+        drop(registry);
+        let page = paginate(entries, cursor, self.config.page_size)?;
+        let prompts: Vec<Value> = page
+            .items
+            .iter()
+            .map(|(name, prompt)| {
+                let description = prompt.description().to_string();
+                let arguments = prompt.arguments();
+                serde_json::json!({
+                    "name": name,
+                    "description": description,
+                    "arguments": arguments,
+                })
+            })
+            .collect();
+        Ok(list_result("prompts", prompts, page.next_cursor))
+    }
+
+    pub(crate) async fn handle_get_prompt(
+        &self,
+        payload: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, HttpError> {
+        let name = payload
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| HttpError::bad_request("Missing 'name' field in request".to_string()))?;
+
+        // Validate prompt name
+        validate_prompt_name(name)
+            .map_err(|e| HttpError::bad_request(format!("Invalid prompt name: {}", e)))?;
+
+        let arguments = payload
+            .get("arguments")
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        let prompt = {
+            let registry = self.registry();
+            let prompt = registry
+                .prompts
+                .get(name)
+                .cloned()
+                .ok_or_else(|| HttpError::not_found(format!("Prompt '{}' not found", name)))?;
+            if !registry.prompt_allowed(name, ctx) {
+                tracing::info!("Access policy denied prompt '{}'", name);
+                return Err(HttpError::forbidden(format!(
+                    "Not allowed to get prompt '{}'",
+                    name
+                )));
+            }
+            prompt
+        };
+
+        // Render prompt with configured timeout
+        let timeout_duration = self.config.prompt_timeout;
+        let deadline = ctx.start_deadline(timeout_duration);
+        let render_result =
+            tokio::time::timeout_at(deadline, prompt.render_with_context(&arguments, ctx)).await;
+
+        match render_result {
+            Ok(Ok(content)) => Ok(serde_json::json!({
+                "messages": [{
+                    "role": "user",
+                    "content": {
+                        "type": "text",
+                        "text": content
+                    }
+                }]
+            })),
+            Ok(Err(e)) => {
+                tracing::error!("Prompt render error: {}", e);
+                Err(HttpError::internal(format!("Prompt render failed: {}", e)))
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Prompt '{}' render timed out after {:?}",
+                    name,
+                    timeout_duration
+                );
+                Err(HttpError::internal(format!(
+                    "Prompt '{}' render timed out after {:?}",
+                    name, timeout_duration
+                )))
+            }
+        }
+    }
 }
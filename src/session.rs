@@ -0,0 +1,415 @@
+//! Per-connection MCP session state.
+//!
+//! A session records whether the client has completed the `initialize`
+//! handshake and what was negotiated. Requests other than `initialize` and
+//! `ping` are rejected until the handshake has happened.
+//!
+//! Each session also owns an outbound channel for server-to-client messages
+//! (notifications and requests). Transports subscribe to it to deliver those
+//! messages, e.g. over an SSE stream or a WebSocket. Responses to
+//! server-initiated requests are routed back to the waiting caller.
+//!
+//! Client requests that are still running are tracked by id so that
+//! `notifications/cancelled` can abort them.
+//!
+//! Sessions also remember which resource URIs the client has subscribed to.
+
+use crate::error::ClientRequestError;
+use crate::jsonrpc::{JsonRpcResponse, RequestId, JSONRPC_VERSION};
+use crate::lifecycle::LoggingLevel;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, oneshot};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// Capacity of the per-session outbound message buffer.
+const OUTBOUND_CAPACITY: usize = 256;
+
+/// State of a single client connection.
+#[derive(Debug)]
+pub struct Session {
+    id: String,
+    state: Mutex<SessionState>,
+    last_activity: Mutex<Instant>,
+    outbound: broadcast::Sender<Value>,
+    pending: Mutex<HashMap<RequestId, PendingRequest>>,
+    /// Set once the client can no longer answer server-initiated requests.
+    requests_closed: AtomicBool,
+    next_request_id: AtomicI64,
+    in_flight: Mutex<HashMap<RequestId, CancellationToken>>,
+    subscriptions: Mutex<HashSet<String>>,
+}
+
+type PendingRequest = oneshot::Sender<Result<Value, ClientRequestError>>;
+
+#[derive(Debug, Default)]
+struct SessionState {
+    protocol_version: Option<String>,
+    client_info: Option<Value>,
+    client_capabilities: Option<Value>,
+    client_ready: bool,
+    log_level: Option<LoggingLevel>,
+}
+
+impl Session {
+    /// Create a new, uninitialized session with a random UUID v4 identifier.
+    pub fn new() -> Self {
+        let (outbound, _) = broadcast::channel(OUTBOUND_CAPACITY);
+        Self {
+            id: Uuid::new_v4().to_string(),
+            state: Mutex::new(SessionState::default()),
+            last_activity: Mutex::new(Instant::now()),
+            outbound,
+            pending: Mutex::new(HashMap::new()),
+            requests_closed: AtomicBool::new(false),
+            next_request_id: AtomicI64::new(1),
+            in_flight: Mutex::new(HashMap::new()),
+            subscriptions: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// The session identifier (sent to HTTP clients as `Mcp-Session-Id`).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns `true` once the server has answered the client's `initialize` request.
+    pub fn is_initialized(&self) -> bool {
+        self.state().protocol_version.is_some()
+    }
+
+    /// Returns `true` once the client has sent `notifications/initialized`.
+    pub fn is_client_ready(&self) -> bool {
+        self.state().client_ready
+    }
+
+    /// The negotiated protocol version, if initialized.
+    pub fn protocol_version(&self) -> Option<String> {
+        self.state().protocol_version.clone()
+    }
+
+    /// The `clientInfo` sent by the client during initialization.
+    pub fn client_info(&self) -> Option<Value> {
+        self.state().client_info.clone()
+    }
+
+    /// The `capabilities` sent by the client during initialization.
+    pub fn client_capabilities(&self) -> Option<Value> {
+        self.state().client_capabilities.clone()
+    }
+
+    /// The minimum log level set by the client with `logging/setLevel`.
+    ///
+    /// `None` until the client sets a level, in which case all messages are sent.
+    pub fn log_level(&self) -> Option<LoggingLevel> {
+        self.state().log_level
+    }
+
+    pub(crate) fn set_log_level(&self, level: LoggingLevel) {
+        self.state().log_level = Some(level);
+    }
+
+    /// Returns `true` if the client has subscribed to updates of `uri`.
+    pub fn is_subscribed(&self, uri: &str) -> bool {
+        lock(&self.subscriptions).contains(uri)
+    }
+
+    pub(crate) fn subscribe_resource(&self, uri: &str) {
+        lock(&self.subscriptions).insert(uri.to_string());
+    }
+
+    pub(crate) fn unsubscribe_resource(&self, uri: &str) {
+        lock(&self.subscriptions).remove(uri);
+    }
+
+    /// Send a JSON-RPC notification to the client.
+    ///
+    /// Returns `false` if no transport is currently listening, in which case the
+    /// notification is dropped.
+    pub fn notify(&self, method: &str, params: Value) -> bool {
+        self.send(serde_json::json!({
+            "jsonrpc": JSONRPC_VERSION,
+            "method": method,
+            "params": params,
+        }))
+    }
+
+    /// Send a JSON-RPC request to the client and wait for its response.
+    ///
+    /// Fails immediately if no transport is listening, and with
+    /// [`ClientRequestError::Closed`] if the session ends first. There is no
+    /// built-in timeout; wrap the call in [`tokio::time::timeout`] if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(session: &axum_mcp::Session) -> Result<(), axum_mcp::ClientRequestError> {
+    /// let roots = session.request("roots/list", serde_json::json!({})).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request(&self, method: &str, params: Value) -> Result<Value, ClientRequestError> {
+        let id = RequestId::Number(self.next_request_id.fetch_add(1, Ordering::Relaxed));
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut pending = lock(&self.pending);
+            if self.requests_closed.load(Ordering::SeqCst) {
+                return Err(ClientRequestError::Closed);
+            }
+            pending.insert(id.clone(), tx);
+        }
+
+        // Forget the request if the caller stops waiting
+        let _guard = PendingGuard {
+            session: self,
+            id: id.clone(),
+        };
+        let sent = self.send(serde_json::json!({
+            "jsonrpc": JSONRPC_VERSION,
+            "id": id,
+            "method": method,
+            "params": params,
+        }));
+        if !sent {
+            return Err(ClientRequestError::NotConnected);
+        }
+        rx.await.unwrap_or(Err(ClientRequestError::Closed))
+    }
+
+    /// Deliver a client's response to the matching server-initiated request.
+    pub(crate) fn resolve(&self, response: JsonRpcResponse) {
+        let Some(id) = response.id else {
+            tracing::warn!("Ignoring client response without an id");
+            return;
+        };
+        let Some(tx) = lock(&self.pending).remove(&id) else {
+            tracing::debug!(id = %id, "Ignoring response to unknown request");
+            return;
+        };
+        let result = match response.error {
+            Some(error) => Err(ClientRequestError::Rejected(error)),
+            None => Ok(response.result.unwrap_or(Value::Null)),
+        };
+        let _ = tx.send(result);
+    }
+
+    /// Track a client request while it runs.
+    ///
+    /// The request is cancelled if the returned guard is dropped before
+    /// [`InFlightRequest::finish`] is called, e.g. because the client disconnected.
+    pub(crate) fn begin_request(&self, id: RequestId) -> InFlightRequest<'_> {
+        let token = CancellationToken::new();
+        lock(&self.in_flight).insert(id.clone(), token.clone());
+        InFlightRequest {
+            session: self,
+            id,
+            token,
+            finished: false,
+        }
+    }
+
+    /// Cancel an in-flight client request, returning `false` if it is not running.
+    pub(crate) fn cancel_request(&self, id: &RequestId) -> bool {
+        match lock(&self.in_flight).get(id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Fail every outstanding server-initiated request, and any sent later.
+    ///
+    /// Client requests keep running, so their responses can still be delivered.
+    pub(crate) fn close_requests(&self) {
+        let mut pending = lock(&self.pending);
+        self.requests_closed.store(true, Ordering::SeqCst);
+        for (_, tx) in pending.drain() {
+            let _ = tx.send(Err(ClientRequestError::Closed));
+        }
+    }
+
+    /// Fail every outstanding server-initiated request and cancel every
+    /// in-flight client request.
+    pub(crate) fn close(&self) {
+        self.close_requests();
+        for token in lock(&self.in_flight).values() {
+            token.cancel();
+        }
+    }
+
+    /// Queue a raw JSON-RPC message for delivery to the client.
+    pub(crate) fn send(&self, message: Value) -> bool {
+        self.outbound.send(message).is_ok()
+    }
+
+    /// Subscribe to server-to-client messages for this session.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<Value> {
+        self.outbound.subscribe()
+    }
+
+    pub(crate) fn initialize(
+        &self,
+        protocol_version: &str,
+        client_info: Option<Value>,
+        client_capabilities: Option<Value>,
+    ) {
+        let mut state = self.state();
+        state.protocol_version = Some(protocol_version.to_string());
+        state.client_info = client_info;
+        state.client_capabilities = client_capabilities;
+        state.client_ready = false;
+    }
+
+    pub(crate) fn mark_client_ready(&self) {
+        self.state().client_ready = true;
+    }
+
+    /// Record client activity, resetting the idle timer.
+    pub(crate) fn touch(&self) {
+        *lock(&self.last_activity) = Instant::now();
+    }
+
+    /// A session is idle if it has seen no activity for `timeout` and has no open streams.
+    fn is_expired(&self, timeout: Duration) -> bool {
+        self.outbound.receiver_count() == 0 && lock(&self.last_activity).elapsed() > timeout
+    }
+
+    fn state(&self) -> MutexGuard<'_, SessionState> {
+        lock(&self.state)
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Removes an abandoned request from the pending table.
+struct PendingGuard<'a> {
+    session: &'a Session,
+    id: RequestId,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        lock(&self.session.pending).remove(&self.id);
+    }
+}
+
+/// A client request being handled; see [`Session::begin_request`].
+pub(crate) struct InFlightRequest<'a> {
+    session: &'a Session,
+    id: RequestId,
+    token: CancellationToken,
+    finished: bool,
+}
+
+impl InFlightRequest<'_> {
+    /// The token cancelled when the client gives up on the request.
+    pub(crate) fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Mark the request as answered, so dropping the guard does not cancel it.
+    pub(crate) fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        lock(&self.session.in_flight).remove(&self.id);
+        if !self.finished {
+            self.token.cancel();
+        }
+    }
+}
+
+/// Registry of live sessions, keyed by session id.
+///
+/// Shared by every transport of a server. Obtain it with
+/// [`McpServer::session_manager`](crate::McpServer::session_manager) to reach
+/// connected clients from outside a request, e.g. to push notifications.
+#[derive(Debug, Default)]
+pub struct SessionManager {
+    sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+impl SessionManager {
+    /// Look up a session by id without counting it as activity.
+    pub fn session(&self, id: &str) -> Option<Arc<Session>> {
+        lock(&self.sessions).get(id).cloned()
+    }
+
+    /// All registered sessions.
+    pub fn sessions(&self) -> Vec<Arc<Session>> {
+        lock(&self.sessions).values().cloned().collect()
+    }
+
+    /// Send a notification to every session, returning how many had a listener.
+    pub fn notify_all(&self, method: &str, params: Value) -> usize {
+        self.sessions()
+            .iter()
+            .filter(|session| session.notify(method, params.clone()))
+            .count()
+    }
+
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create and register a new session, dropping any that have expired.
+    pub(crate) fn create(&self, idle_timeout: Duration) -> Arc<Session> {
+        let session = Arc::new(Session::new());
+        let mut sessions = lock(&self.sessions);
+        sessions.retain(|_, s| {
+            let expired = s.is_expired(idle_timeout);
+            if expired {
+                s.close();
+            }
+            !expired
+        });
+        sessions.insert(session.id().to_string(), Arc::clone(&session));
+        session
+    }
+
+    /// Look up a live session and mark it active.
+    ///
+    /// Expired sessions are removed and reported as missing.
+    pub(crate) fn get(&self, id: &str, idle_timeout: Duration) -> Option<Arc<Session>> {
+        let mut sessions = lock(&self.sessions);
+        let session = sessions.get(id)?;
+        if session.is_expired(idle_timeout) {
+            tracing::debug!(session_id = %id, "session expired");
+            session.close();
+            sessions.remove(id);
+            return None;
+        }
+        session.touch();
+        Some(Arc::clone(session))
+    }
+
+    /// Remove a session, returning it if it existed.
+    pub(crate) fn remove(&self, id: &str) -> Option<Arc<Session>> {
+        let session = lock(&self.sessions).remove(id)?;
+        session.close();
+        Some(session)
+    }
+
+    /// Number of registered sessions.
+    pub(crate) fn len(&self) -> usize {
+        lock(&self.sessions).len()
+    }
+}
+
+/// Lock a mutex, recovering the data if a previous holder panicked.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -29,13 +29,16 @@
 //! # }
 //! ```
 
+use crate::context::RequestContext;
 use crate::tool::Tool;
+use crate::tool_error::ToolError;
 use serde_json::Value;
 
 /// Test a tool with given arguments.
 ///
-/// This is a convenience function that calls `tool.call()` with the provided arguments
-/// and returns the result. Useful for unit testing tools without starting a full server.
+/// This is a convenience function that calls `tool.call_with_context()` with the
+/// provided arguments and a detached [`RequestContext`], and returns the result.
+/// Useful for unit testing tools without starting a full server.
 ///
 /// # Example
 ///
@@ -64,13 +67,16 @@ use serde_json::Value;
 /// # }
 /// ```
 pub async fn test_tool(tool: &dyn Tool, arguments: Value) -> Result<Value, String> {
-    tool.call(&arguments).await
+    tool.invoke(&arguments, &RequestContext::new())
+        .await
+        .map_err(ToolError::into_message)?
+        .into_value()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Tool;
+    use crate::ToolResult;
     use async_trait::async_trait;
     use serde_json::json;
 
@@ -108,4 +114,39 @@ mod tests {
         let result = test_tool(&tool, args).await.unwrap();
         assert_eq!(result["result"], "test");
     }
+
+    struct InvokeOnlyTool;
+
+    #[async_trait]
+    impl Tool for InvokeOnlyTool {
+        fn description(&self) -> &str {
+            "Tool implementing only invoke"
+        }
+
+        fn schema(&self) -> Value {
+            json!({ "type": "object" })
+        }
+
+        async fn invoke(
+            &self,
+            arguments: &Value,
+            _ctx: &RequestContext,
+        ) -> Result<ToolResult, ToolError> {
+            match arguments.get("value") {
+                Some(value) => Ok(ToolResult::structured(json!({ "result": value }))),
+                None => Err(ToolError::execution_failed("No value given")),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_test_tool_through_invoke() {
+        let tool = InvokeOnlyTool;
+        let result = test_tool(&tool, json!({"value": "test"})).await.unwrap();
+        assert_eq!(result["result"], "test");
+        assert_eq!(tool.call(&json!({"value": 1})).await.unwrap()["result"], 1);
+
+        let err = test_tool(&tool, json!({})).await.unwrap_err();
+        assert_eq!(err, "No value given");
+    }
 }
@@ -42,8 +42,14 @@
 //! }
 //! ```
 
+use crate::content::ToolResult;
+use crate::context::RequestContext;
+use crate::tool_error::ToolError;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
 
 /// A tool that can be called by MCP clients.
 ///
@@ -52,7 +58,11 @@ use serde_json::Value;
 ///
 /// - A human-readable description (used by agents to understand when to call the tool)
 /// - A JSON Schema defining the tool's parameters
-/// - An async execution function that processes arguments and returns results
+/// - An async execution function that processes arguments and returns results:
+///   at least one of [`call`](Tool::call),
+///   [`call_with_context`](Tool::call_with_context), or [`invoke`](Tool::invoke).
+///   The others default to it, so every entry point works whichever one a
+///   tool implements; a tool that implements none of them recurses forever.
 ///
 /// # JSON Schema Format
 ///
@@ -62,7 +72,9 @@ use serde_json::Value;
 /// # Error Handling
 ///
 /// Return `Err(String)` to indicate tool execution failure. The error message
-/// will be returned to the agent.
+/// will be returned to the agent. For structured errors (such as an invalid
+/// parameter, reported as HTTP 400), implement [`invoke`](Tool::invoke)
+/// instead and return a [`ToolError`].
 #[async_trait]
 pub trait Tool: Send + Sync {
     /// Get the tool's description.
@@ -99,6 +111,26 @@ pub trait Tool: Send + Sync {
     /// ```
     fn schema(&self) -> Value;
 
+    /// Get the JSON Schema of the tool's structured output, if it declares one.
+    ///
+    /// The schema is advertised in `tools/list` as `outputSchema`. Tools that
+    /// declare one return their value as `structuredContent` (with a text
+    /// fallback), which the server validates against the schema when
+    /// [`ServerConfig::validate_output`](crate::ServerConfig::validate_output) is set.
+    fn output_schema(&self) -> Option<Value> {
+        None
+    }
+
+    /// Get hints about the tool's behavior, if it declares any.
+    ///
+    /// The annotations are advertised in `tools/list`, where clients use them
+    /// to decide which calls need human confirmation. With a
+    /// [`ServerConfig::tool_filter`](crate::ServerConfig::tool_filter) set,
+    /// the server itself refuses calls based on them.
+    fn annotations(&self) -> Option<ToolAnnotations> {
+        None
+    }
+
     /// Call the tool with the given arguments.
     ///
     /// # Arguments
@@ -126,5 +158,251 @@ pub trait Tool: Send + Sync {
     /// Ok(json!({ "result": "success" }))
     /// # }
     /// ```
-    async fn call(&self, arguments: &Value) -> Result<Value, String>;
+    ///
+    /// # Note
+    ///
+    /// Tools that implement [`call_with_context`](Tool::call_with_context) or
+    /// [`invoke`](Tool::invoke) instead need not implement this method. The
+    /// default calls [`invoke`](Tool::invoke) with a detached
+    /// [`RequestContext`] and converts its result back into a value.
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        self.invoke(arguments, &RequestContext::new())
+            .await
+            .map_err(ToolError::into_message)?
+            .into_value()
+    }
+
+    /// Call the tool with access to the request context.
+    ///
+    /// The default implementation ignores the context and delegates to
+    /// [`call`](Tool::call); override it to report progress via
+    /// [`RequestContext::report_progress`] or to see who is calling (see
+    /// [`RequestContext::principal`]).
+    async fn call_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        let _ = ctx;
+        self.call(arguments).await
+    }
+
+    /// Call the tool and return its result as content blocks.
+    ///
+    /// This is what the server invokes. The default implementation wraps the
+    /// value from [`call_with_context`](Tool::call_with_context) in a single
+    /// text block, and also returns it as structured content if the tool has an
+    /// [`output_schema`](Tool::output_schema). Override it to return images,
+    /// audio, embedded resources, or resource links (see [`Content`](crate::Content)).
+    ///
+    /// Errors are [`ToolError`]s, so `?` works on any error convertible into
+    /// one; a plain `String` becomes [`ToolError::ExecutionFailed`]. Over
+    /// JSON-RPC, execution failures are sent to the model as an `isError`
+    /// result, while parameter errors and timeouts are reported as protocol
+    /// errors with a stable `error_type`.
+    async fn invoke(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        let value = self.call_with_context(arguments, ctx).await?;
+        Ok(if self.output_schema().is_some() {
+            ToolResult::structured(value)
+        } else {
+            ToolResult::from(value)
+        })
+    }
+}
+
+/// Hints describing a tool's behavior.
+///
+/// Hints are not guarantees: clients should not rely on them for tools from
+/// servers they do not trust. Unset hints take the MCP defaults, reflected by
+/// [`is_read_only`](Self::is_read_only) and [`is_destructive`](Self::is_destructive).
+///
+/// # Example
+///
+/// ```
+/// use axum_mcp::ToolAnnotations;
+///
+/// let annotations = ToolAnnotations::new()
+///     .with_title("Delete Record")
+///     .with_destructive_hint(true)
+///     .with_idempotent_hint(true);
+/// assert!(annotations.is_destructive());
+///
+/// let json = serde_json::to_value(&annotations).unwrap();
+/// assert_eq!(json["destructiveHint"], true);
+/// assert!(json.get("readOnlyHint").is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    /// Human-readable title for the tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The tool does not modify its environment (default: `false`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only_hint: Option<bool>,
+    /// The tool may perform destructive updates, rather than only additive
+    /// ones (default: `true`). Meaningful only when not read-only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destructive_hint: Option<bool>,
+    /// Calling the tool repeatedly with the same arguments has no additional
+    /// effect (default: `false`). Meaningful only when not read-only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotent_hint: Option<bool>,
+    /// The tool interacts with external entities, such as the web
+    /// (default: `true`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_world_hint: Option<bool>,
+}
+
+impl ToolAnnotations {
+    /// Create annotations with every hint unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the title (builder style).
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the read-only hint (builder style).
+    pub fn with_read_only_hint(mut self, read_only: bool) -> Self {
+        self.read_only_hint = Some(read_only);
+        self
+    }
+
+    /// Set the destructive hint (builder style).
+    pub fn with_destructive_hint(mut self, destructive: bool) -> Self {
+        self.destructive_hint = Some(destructive);
+        self
+    }
+
+    /// Set the idempotent hint (builder style).
+    pub fn with_idempotent_hint(mut self, idempotent: bool) -> Self {
+        self.idempotent_hint = Some(idempotent);
+        self
+    }
+
+    /// Set the open-world hint (builder style).
+    pub fn with_open_world_hint(mut self, open_world: bool) -> Self {
+        self.open_world_hint = Some(open_world);
+        self
+    }
+
+    /// Whether the tool declares that it does not modify its environment.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only_hint.unwrap_or(false)
+    }
+
+    /// Whether the tool may perform destructive updates.
+    ///
+    /// True unless the tool is read-only or explicitly non-destructive.
+    pub fn is_destructive(&self) -> bool {
+        !self.is_read_only() && self.destructive_hint.unwrap_or(true)
+    }
+}
+
+/// Decides, from a tool's annotations, whether the server lets it be called.
+///
+/// Set it with [`ServerConfig::with_tool_filter`](crate::ServerConfig::with_tool_filter).
+/// Tools without annotations are judged by [`ToolAnnotations::default`], so
+/// every hint takes its MCP default. Refused calls fail with `403 Forbidden`
+/// (JSON-RPC error [`FORBIDDEN`](crate::jsonrpc::FORBIDDEN)); the tools stay listed.
+///
+/// # Example
+///
+/// ```
+/// use axum_mcp::{ToolAnnotations, ToolFilter};
+///
+/// // Tools without annotations may be destructive, so they are refused too
+/// let filter = ToolFilter::deny_destructive();
+/// assert!(!filter.allows(&ToolAnnotations::new()));
+/// assert!(filter.allows(&ToolAnnotations::new().with_destructive_hint(false)));
+///
+/// // Only refuse tools that explicitly declare `destructiveHint: true`
+/// let lenient = ToolFilter::new(|annotations| annotations.destructive_hint != Some(true));
+/// assert!(lenient.allows(&ToolAnnotations::new()));
+/// ```
+#[derive(Clone)]
+pub struct ToolFilter {
+    rule: FilterRule,
+}
+
+#[derive(Clone)]
+enum FilterRule {
+    ReadOnly,
+    DenyDestructive,
+    Custom(Arc<dyn Fn(&ToolAnnotations) -> bool + Send + Sync>),
+}
+
+impl ToolFilter {
+    /// Allow the tools for which `check` returns `true`.
+    pub fn new<F>(check: F) -> Self
+    where
+        F: Fn(&ToolAnnotations) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            rule: FilterRule::Custom(Arc::new(check)),
+        }
+    }
+
+    /// Allow only tools annotated with `readOnlyHint: true`.
+    pub fn read_only() -> Self {
+        Self {
+            rule: FilterRule::ReadOnly,
+        }
+    }
+
+    /// Refuse tools that may be destructive, see
+    /// [`ToolAnnotations::is_destructive`]. Tools that leave the hint unset
+    /// are refused too, unless they are read-only.
+    pub fn deny_destructive() -> Self {
+        Self {
+            rule: FilterRule::DenyDestructive,
+        }
+    }
+
+    /// Returns `true` if a tool with these annotations may be called.
+    pub fn allows(&self, annotations: &ToolAnnotations) -> bool {
+        match &self.rule {
+            FilterRule::ReadOnly => annotations.is_read_only(),
+            FilterRule::DenyDestructive => !annotations.is_destructive(),
+            FilterRule::Custom(check) => check(annotations),
+        }
+    }
+
+    /// Whether this is the [`read_only`](Self::read_only) filter.
+    pub(crate) fn is_read_only(&self) -> bool {
+        matches!(self.rule, FilterRule::ReadOnly)
+    }
+
+    /// Why a call to the tool `name` was refused.
+    pub(crate) fn refusal(&self, name: &str) -> String {
+        match &self.rule {
+            FilterRule::ReadOnly => format!(
+                "Tool '{}' is not read-only and the server is in read-only mode",
+                name
+            ),
+            FilterRule::DenyDestructive => format!(
+                "Tool '{}' is destructive and the server refuses destructive tools",
+                name
+            ),
+            FilterRule::Custom(_) => format!("Tool '{}' is disabled on this server", name),
+        }
+    }
+}
+
+impl fmt::Debug for ToolFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rule {
+            FilterRule::ReadOnly => f.write_str("ToolFilter::ReadOnly"),
+            FilterRule::DenyDestructive => f.write_str("ToolFilter::DenyDestructive"),
+            FilterRule::Custom(_) => f.write_str("ToolFilter::Custom"),
+        }
+    }
 }
@@ -1,8 +1,14 @@
 //! Structured error types for tool execution.
 //!
-//! Provides more detailed error information than simple `String` errors.
+//! Provides more detailed error information than simple `String` errors. Tools
+//! fail with a [`ToolError`] from [`Tool::invoke`](crate::Tool::invoke); the
+//! server maps it to an HTTP status and a [`ToolErrorResponse`] body (or a
+//! JSON-RPC error carrying the same `error_type`). Over JSON-RPC, execution
+//! failures are the exception: they reach the model as an `isError` result
+//! instead.
 
 use serde::Serialize;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur during tool execution.
@@ -36,7 +42,7 @@ pub enum ToolError {
     #[error("Execution failed: {0}")]
     ExecutionFailed(String),
 
-    /// Tool execution timed out.
+    /// Tool execution timed out after the given number of seconds.
     #[error("Execution timed out after {0} seconds")]
     Timeout(u64),
 }
@@ -77,6 +83,36 @@ impl ToolError {
     pub fn timeout(seconds: u64) -> Self {
         Self::Timeout(seconds)
     }
+
+    /// Create a timeout error from a duration, rounded up to whole seconds
+    /// so that a sub-second timeout is not reported as zero.
+    pub fn timeout_after(after: Duration) -> Self {
+        Self::Timeout(after.as_secs() + u64::from(after.subsec_nanos() > 0))
+    }
+
+    /// [`error_type`](Self::error_type) of [`MissingParameter`](Self::MissingParameter).
+    pub const MISSING_PARAMETER: &'static str = "missing_parameter";
+    /// [`error_type`](Self::error_type) of [`InvalidType`](Self::InvalidType).
+    pub const INVALID_TYPE: &'static str = "invalid_type";
+    /// [`error_type`](Self::error_type) of [`InvalidValue`](Self::InvalidValue).
+    pub const INVALID_VALUE: &'static str = "invalid_value";
+    /// [`error_type`](Self::error_type) of [`ExecutionFailed`](Self::ExecutionFailed).
+    pub const EXECUTION_FAILED: &'static str = "execution_failed";
+    /// [`error_type`](Self::error_type) of [`Timeout`](Self::Timeout).
+    pub const TIMEOUT: &'static str = "timeout";
+
+    /// Get a stable, machine-readable code for the kind of error.
+    ///
+    /// One of the associated constants, e.g. [`ToolError::TIMEOUT`].
+    pub fn error_type(&self) -> &'static str {
+        match self {
+            ToolError::MissingParameter(_) => Self::MISSING_PARAMETER,
+            ToolError::InvalidType { .. } => Self::INVALID_TYPE,
+            ToolError::InvalidValue { .. } => Self::INVALID_VALUE,
+            ToolError::ExecutionFailed(_) => Self::EXECUTION_FAILED,
+            ToolError::Timeout(_) => Self::TIMEOUT,
+        }
+    }
 }
 
 impl From<ToolError> for String {
@@ -85,6 +121,19 @@ impl From<ToolError> for String {
     }
 }
 
+/// Plain error messages are execution failures.
+impl From<String> for ToolError {
+    fn from(msg: String) -> Self {
+        Self::ExecutionFailed(msg)
+    }
+}
+
+impl From<&str> for ToolError {
+    fn from(msg: &str) -> Self {
+        Self::ExecutionFailed(msg.to_string())
+    }
+}
+
 /// HTTP status code mapping for tool errors.
 impl ToolError {
     /// Get the appropriate HTTP status code for this error.
@@ -99,16 +148,25 @@ impl ToolError {
             ToolError::Timeout(_) => 504,         // Gateway Timeout
         }
     }
+
+    /// The message a plain `call` would have returned: the text of an
+    /// execution failure as is, and the description of any other error.
+    pub(crate) fn into_message(self) -> String {
+        match self {
+            ToolError::ExecutionFailed(msg) => msg,
+            err => err.to_string(),
+        }
+    }
 }
 
 /// Structured error response for tool errors.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ToolErrorResponse {
     /// Error code (HTTP status code).
     pub code: u16,
     /// Error message.
     pub message: String,
-    /// Error type.
+    /// Machine-readable error code, see [`ToolError::error_type`].
     pub error_type: String,
     /// Optional error details.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,7 +178,7 @@ impl From<ToolError> for ToolErrorResponse {
         Self {
             code: err.status_code(),
             message: err.to_string(),
-            error_type: format!("{:?}", err),
+            error_type: err.error_type().to_string(),
             details: match &err {
                 ToolError::InvalidType {
                     param,
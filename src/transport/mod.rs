@@ -0,0 +1,135 @@
+//! Transports that carry MCP JSON-RPC messages between clients and the server.
+//!
+//! All transports share the same dispatch logic in [`McpServer`](crate::McpServer);
+//! they only differ in how messages are framed and delivered.
+
+pub mod stdio;
+pub mod streamable_http;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+use crate::context::{HttpParts, Outlet};
+use crate::jsonrpc::{JsonRpcError, JsonRpcResponse};
+use crate::server::McpServer;
+use crate::session::Session;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinSet;
+
+/// One session carried over a single long-lived, bidirectional connection.
+///
+/// Used by the stdio and WebSocket transports: incoming messages are handled
+/// concurrently, and their responses are merged with server-initiated messages
+/// into an [`Outbound`] queue for the connection's writer.
+pub(crate) struct Connection {
+    server: Arc<McpServer>,
+    session: Arc<Session>,
+    http: HttpParts,
+    responses: mpsc::UnboundedSender<Value>,
+    in_flight: JoinSet<()>,
+}
+
+impl Connection {
+    /// Register a new session and return the connection with its outbound queue.
+    ///
+    /// `http` is the HTTP request that opened the connection, if any; every
+    /// message on the connection is handled as if it arrived with it.
+    pub(crate) fn open(server: Arc<McpServer>, http: HttpParts) -> (Self, Outbound) {
+        let session = server.sessions.create(server.config().session_timeout);
+        let (responses, responses_rx) = mpsc::unbounded_channel();
+        let outbound = Outbound {
+            responses: responses_rx,
+            messages: session.subscribe(),
+            messages_open: true,
+        };
+        let connection = Self {
+            server,
+            session,
+            http,
+            responses,
+            in_flight: JoinSet::new(),
+        };
+        (connection, outbound)
+    }
+
+    /// Handle one framed message from the client.
+    pub(crate) fn handle_text(&mut self, text: &str) {
+        let message: Value = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(e) => {
+                let response = JsonRpcResponse::error(
+                    None,
+                    JsonRpcError::parse_error(format!("Parse error: {}", e)),
+                );
+                self.respond(response);
+                return;
+            }
+        };
+
+        let server = Arc::clone(&self.server);
+        let session = Arc::clone(&self.session);
+        let http = self.http.clone();
+        let responses = self.responses.clone();
+        self.in_flight.spawn(async move {
+            let outlet = Some(Outlet::new(responses.clone()));
+            if let Some(response) = server.handle_jsonrpc(&session, message, outlet, http).await {
+                send_response(&responses, response);
+            }
+        });
+    }
+
+    /// End the session.
+    ///
+    /// With `drain`, in-flight requests are allowed to finish and their
+    /// responses are still queued; otherwise they are aborted. Once this returns,
+    /// the [`Outbound`] queue ends after delivering what is left.
+    pub(crate) async fn close(mut self, drain: bool) {
+        // The client can no longer answer server-initiated requests
+        self.session.close_requests();
+        if !drain {
+            self.in_flight.abort_all();
+        }
+        while self.in_flight.join_next().await.is_some() {}
+        // Only now, as removing the session cancels its in-flight requests
+        self.server.sessions.remove(self.session.id());
+    }
+
+    fn respond(&self, response: JsonRpcResponse) {
+        send_response(&self.responses, response);
+    }
+}
+
+fn send_response(responses: &mpsc::UnboundedSender<Value>, response: JsonRpcResponse) {
+    match serde_json::to_value(response) {
+        Ok(response) => {
+            let _ = responses.send(response);
+        }
+        Err(e) => tracing::error!("Failed to serialize response: {}", e),
+    }
+}
+
+/// Messages waiting to be written to a [`Connection`]'s client.
+pub(crate) struct Outbound {
+    responses: mpsc::UnboundedReceiver<Value>,
+    messages: broadcast::Receiver<Value>,
+    messages_open: bool,
+}
+
+impl Outbound {
+    /// The next message to write, or `None` once the connection has closed.
+    pub(crate) async fn next(&mut self) -> Option<Value> {
+        loop {
+            tokio::select! {
+                response = self.responses.recv() => return response,
+                message = self.messages.recv(), if self.messages_open => match message {
+                    Ok(message) => return Some(message),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Dropped {} outbound messages", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => self.messages_open = false,
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! stdio transport.
+//!
+//! Speaks newline-delimited JSON-RPC over a reader/writer pair, which is how
+//! desktop clients launch local MCP servers as subprocesses. Each line on the
+//! input is one message; each response or notification is written as one line.
+//!
+//! Requests are handled concurrently, so a slow tool does not block the rest
+//! of the session. stdout carries protocol messages only: log output must go
+//! to stderr (see [`McpServer::serve_stdio`]).
+
+use super::{Connection, Outbound};
+use crate::context::HttpParts;
+use crate::error::McpError;
+use crate::server::McpServer;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+
+/// Serve a single MCP session over a newline-delimited JSON-RPC stream.
+///
+/// Returns when `reader` reaches end of input and all in-flight requests have
+/// been answered.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_mcp::{transport::stdio, McpServer};
+///
+/// # async fn example() -> Result<(), axum_mcp::McpError> {
+/// let server = McpServer::new();
+/// stdio::serve_io(server, tokio::io::stdin(), tokio::io::stdout()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn serve_io<R, W>(server: McpServer, reader: R, writer: W) -> Result<(), McpError>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut connection, outbound) = Connection::open(Arc::new(server), HttpParts::default());
+    let writer_task = tokio::spawn(write_messages(writer, outbound));
+
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if !line.trim().is_empty() {
+            connection.handle_text(&line);
+        }
+    }
+
+    tracing::debug!("stdin closed, waiting for in-flight requests");
+    connection.close(true).await;
+    writer_task
+        .await
+        .map_err(|e| McpError::Io(std::io::Error::other(e)))??;
+    Ok(())
+}
+
+/// Write responses and server-initiated messages, one JSON document per line.
+async fn write_messages<W>(mut writer: W, mut outbound: Outbound) -> Result<(), McpError>
+where
+    W: AsyncWrite + Unpin,
+{
+    while let Some(message) = outbound.next().await {
+        let mut line = serde_json::to_vec(&message)?;
+        line.push(b'\n');
+        writer.write_all(&line).await?;
+        writer.flush().await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,257 @@
+//! Streamable HTTP transport.
+//!
+//! Implements the MCP Streamable HTTP transport on a single endpoint
+//! (default `/mcp`):
+//!
+//! - `POST` carries one JSON-RPC message. Requests are answered with either
+//!   `application/json` or a `text/event-stream` response, depending on the
+//!   client's `Accept` header. An event stream also carries notifications
+//!   related to the request, such as progress, ahead of the response.
+//!   Notifications and responses get `202 Accepted`.
+//! - `GET` opens a server-to-client SSE stream for the session.
+//! - `DELETE` ends the session.
+//!
+//! A session is created when the client sends `initialize`; its id is returned
+//! in the `Mcp-Session-Id` header and must accompany every later request.
+//! Sessions expire after [`ServerConfig::session_timeout`](crate::ServerConfig)
+//! of inactivity unless a `GET` stream is open.
+
+use super::send_response;
+use crate::context::{HttpParts, Outlet};
+use crate::jsonrpc::{JsonRpcError, JsonRpcResponse};
+use crate::lifecycle::SUPPORTED_PROTOCOL_VERSIONS;
+use crate::protocol::is_client_response;
+use crate::server::McpServer;
+use crate::session::Session;
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{header, Extensions, HeaderMap, HeaderValue, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Json, Response,
+    },
+};
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{BroadcastStream, UnboundedReceiverStream};
+
+/// Header carrying the session identifier.
+pub const MCP_SESSION_ID_HEADER: &str = "mcp-session-id";
+
+/// Header carrying the negotiated protocol version on requests after initialization.
+pub const MCP_PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+
+/// `POST` handler: deliver one client message.
+pub(crate) async fn post_handler(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    body: Bytes,
+) -> Response {
+    let message: Value = match serde_json::from_slice(&body) {
+        Ok(message) => message,
+        Err(e) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                JsonRpcError::parse_error(format!("Parse error: {}", e)),
+            )
+        }
+    };
+
+    let is_initialize = message.get("method").and_then(|m| m.as_str()) == Some("initialize");
+    let is_notification = message.get("method").is_some() && message.get("id").is_none();
+    let is_client_response = is_client_response(&message);
+
+    let (session, created) = if is_initialize {
+        (
+            server.sessions.create(server.config().session_timeout),
+            true,
+        )
+    } else {
+        if let Err(response) = check_protocol_version(&headers) {
+            return response;
+        }
+        match find_session(&server, &headers) {
+            Ok(session) => (session, false),
+            Err(response) => return response,
+        }
+    };
+
+    let accepts_stream = accepts_event_stream(&headers);
+    let http = HttpParts::new(headers, extensions);
+
+    if is_notification || is_client_response {
+        // Notifications and client responses are acknowledged without a body
+        server.handle_jsonrpc(&session, message, None, http).await;
+        return StatusCode::ACCEPTED.into_response();
+    }
+
+    if !created && accepts_stream {
+        return stream_response(server, session, message, http);
+    }
+
+    // Plain JSON responses cannot carry notifications, so none are collected
+    let Some(response) = server.handle_jsonrpc(&session, message, None, http).await else {
+        return StatusCode::ACCEPTED.into_response();
+    };
+
+    if created && response.error.is_some() {
+        // A failed handshake does not establish a session
+        server.sessions.remove(session.id());
+        return Json(response).into_response();
+    }
+
+    let mut http_response = if accepts_stream {
+        let event = message_event(&response);
+        Sse::new(stream::once(async move { Ok::<_, Infallible>(event) })).into_response()
+    } else {
+        Json(response).into_response()
+    };
+
+    if created {
+        if let Ok(value) = HeaderValue::from_str(session.id()) {
+            http_response
+                .headers_mut()
+                .insert(MCP_SESSION_ID_HEADER, value);
+        }
+    }
+    http_response
+}
+
+/// Answer a request with an SSE stream: related notifications (such as
+/// progress) while it runs, then the response, after which the stream closes.
+fn stream_response(
+    server: Arc<McpServer>,
+    session: Arc<Session>,
+    message: Value,
+    http: HttpParts,
+) -> Response {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let outlet = Some(Outlet::new(tx.clone()));
+        tokio::select! {
+            response = server.handle_jsonrpc(&session, message, outlet, http) => {
+                if let Some(response) = response {
+                    send_response(&tx, response);
+                }
+            }
+            // Dropping the request future cancels it
+            _ = tx.closed() => tracing::debug!("client disconnected, cancelling request"),
+        }
+    });
+
+    let events = UnboundedReceiverStream::new(rx)
+        .map(|message| Ok::<_, Infallible>(message_event(&message)));
+    Sse::new(events).into_response()
+}
+
+/// `GET` handler: open a server-to-client SSE stream for the session.
+pub(crate) async fn get_handler(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+) -> Response {
+    if !accepts_event_stream(&headers) {
+        return StatusCode::NOT_ACCEPTABLE.into_response();
+    }
+    let session = match find_session(&server, &headers) {
+        Ok(session) => session,
+        Err(response) => return response,
+    };
+
+    // The stream holds only the receiver, so it ends once the session is dropped
+    let messages = BroadcastStream::new(session.subscribe()).filter_map(|message| async move {
+        match message {
+            Ok(message) => Some(Ok::<_, Infallible>(message_event(&message))),
+            Err(e) => {
+                tracing::warn!("SSE stream lagged: {}", e);
+                None
+            }
+        }
+    });
+    Sse::new(messages)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// `DELETE` handler: terminate the session.
+pub(crate) async fn delete_handler(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+) -> Response {
+    let session = match find_session(&server, &headers) {
+        Ok(session) => session,
+        Err(response) => return response,
+    };
+    server.sessions.remove(session.id());
+    tracing::debug!(session_id = %session.id(), "session terminated by client");
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// Resolve the session named by the `Mcp-Session-Id` header.
+///
+/// Missing headers are a bad request; unknown or expired sessions are 404 so the
+/// client knows to re-initialize.
+#[allow(clippy::result_large_err)]
+fn find_session(server: &McpServer, headers: &HeaderMap) -> Result<Arc<Session>, Response> {
+    let id = headers
+        .get(MCP_SESSION_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                JsonRpcError::invalid_request("Missing Mcp-Session-Id header"),
+            )
+        })?;
+    server
+        .sessions
+        .get(id, server.config().session_timeout)
+        .ok_or_else(|| {
+            error_response(
+                StatusCode::NOT_FOUND,
+                JsonRpcError::invalid_request(format!("Session '{}' not found or expired", id)),
+            )
+        })
+}
+
+/// Reject requests that name a protocol version this server does not speak.
+#[allow(clippy::result_large_err)]
+fn check_protocol_version(headers: &HeaderMap) -> Result<(), Response> {
+    let Some(version) = headers.get(MCP_PROTOCOL_VERSION_HEADER) else {
+        return Ok(());
+    };
+    let version = version.to_str().unwrap_or_default();
+    if SUPPORTED_PROTOCOL_VERSIONS.contains(&version) {
+        Ok(())
+    } else {
+        Err(error_response(
+            StatusCode::BAD_REQUEST,
+            JsonRpcError::invalid_request(format!("Unsupported protocol version '{}'", version)),
+        ))
+    }
+}
+
+fn accepts_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .any(|v| v.contains("text/event-stream"))
+}
+
+fn message_event(message: &impl serde::Serialize) -> Event {
+    Event::default()
+        .event("message")
+        .json_data(message)
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to serialize SSE message: {}", e);
+            Event::default().event("error")
+        })
+}
+
+fn error_response(status: StatusCode, error: JsonRpcError) -> Response {
+    (status, Json(JsonRpcResponse::error(None, error))).into_response()
+}
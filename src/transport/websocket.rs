@@ -0,0 +1,80 @@
+//! WebSocket transport.
+//!
+//! Served at `{mcp_path}/ws` (default `/mcp/ws`) when the `websocket` feature is
+//! enabled. Each text frame carries one JSON-RPC message in either direction, so
+//! server-initiated notifications and requests share the connection with the
+//! client's requests. The connection is the session: it is created on upgrade
+//! and ends when the socket closes, so no `Mcp-Session-Id` header is involved.
+
+use super::{Connection, Outbound};
+use crate::context::HttpParts;
+use crate::server::McpServer;
+use axum::{
+    extract::{
+        ws::{Message, WebSocket},
+        State, WebSocketUpgrade,
+    },
+    http::{Extensions, HeaderMap},
+    response::Response,
+};
+use futures_util::{
+    sink::SinkExt,
+    stream::{SplitSink, StreamExt},
+};
+use std::sync::Arc;
+
+/// `GET` handler: upgrade to a WebSocket carrying one MCP session.
+///
+/// Handlers on the connection see the headers and extensions of the upgrade
+/// request, so authentication middleware applies to the whole session.
+pub(crate) async fn ws_handler(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let http = HttpParts::new(headers, extensions);
+    upgrade
+        .protocols(["mcp"])
+        .on_upgrade(move |socket| serve_socket(server, socket, http))
+}
+
+async fn serve_socket(server: Arc<McpServer>, socket: WebSocket, http: HttpParts) {
+    let (sink, mut stream) = socket.split();
+    let (mut connection, outbound) = Connection::open(server, http);
+    let writer_task = tokio::spawn(write_messages(sink, outbound));
+
+    while let Some(message) = stream.next().await {
+        match message {
+            Ok(Message::Text(text)) => connection.handle_text(&text),
+            Ok(Message::Binary(bytes)) => match std::str::from_utf8(&bytes) {
+                Ok(text) => connection.handle_text(text),
+                Err(_) => tracing::warn!("Ignoring non-UTF-8 binary WebSocket frame"),
+            },
+            Ok(Message::Close(_)) => break,
+            // Pings are answered by the WebSocket implementation
+            Ok(Message::Ping(_) | Message::Pong(_)) => {}
+            Err(e) => {
+                tracing::debug!("WebSocket receive error: {}", e);
+                break;
+            }
+        }
+    }
+
+    // Nobody is left to read responses, so in-flight requests are abandoned
+    connection.close(false).await;
+    if let Err(e) = writer_task.await {
+        tracing::error!("WebSocket writer task failed: {}", e);
+    }
+}
+
+/// Write responses and server-initiated messages as text frames.
+async fn write_messages(mut sink: SplitSink<WebSocket, Message>, mut outbound: Outbound) {
+    while let Some(message) = outbound.next().await {
+        if let Err(e) = sink.send(Message::Text(message.to_string())).await {
+            tracing::debug!("WebSocket send error: {}", e);
+            return;
+        }
+    }
+    let _ = sink.close().await;
+}
@@ -0,0 +1,255 @@
+//! Tools with typed arguments and output.
+//!
+//! A [`TypedTool`] declares its arguments as a Rust type instead of a
+//! hand-written JSON Schema. The schema is derived with [`schemars`], and the
+//! arguments are deserialized before [`TypedTool::call`] runs, so the schema
+//! and the parsing code cannot drift apart. Every `TypedTool` is a [`Tool`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use axum_mcp::{McpServer, RequestContext, ToolError, TypedTool};
+//! use async_trait::async_trait;
+//! use schemars::JsonSchema;
+//! use serde::{Deserialize, Serialize};
+//!
+//! /// Arguments of the search tool.
+//! #[derive(Deserialize, JsonSchema)]
+//! struct SearchArgs {
+//!     /// Search query
+//!     query: String,
+//!     /// Maximum results
+//!     #[serde(default = "default_limit")]
+//!     limit: u32,
+//! }
+//! # fn default_limit() -> u32 { 10 }
+//!
+//! #[derive(Serialize)]
+//! struct SearchResults {
+//!     results: Vec<String>,
+//! }
+//!
+//! struct SearchTool;
+//!
+//! #[async_trait]
+//! impl TypedTool for SearchTool {
+//!     type Args = SearchArgs;
+//!     type Output = SearchResults;
+//!
+//!     fn description(&self) -> &str {
+//!         "Search the index"
+//!     }
+//!
+//!     async fn call(
+//!         &self,
+//!         args: SearchArgs,
+//!         _ctx: &RequestContext,
+//!     ) -> Result<SearchResults, ToolError> {
+//!         let results = (0..args.limit).map(|i| format!("{} #{}", args.query, i)).collect();
+//!         Ok(SearchResults { results })
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = McpServer::new().tool("search", SearchTool)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::content::ToolResult;
+use crate::context::RequestContext;
+use crate::tool::{Tool, ToolAnnotations};
+use crate::tool_error::ToolError;
+use async_trait::async_trait;
+use schemars::gen::SchemaSettings;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A tool whose arguments and output are Rust types.
+///
+/// The input schema is generated from [`Args`](TypedTool::Args) (doc comments
+/// become descriptions, `Option` fields and `#[serde(default)]` fields are not
+/// required). `Args` should be a struct, since tool arguments are a JSON object.
+///
+/// Arguments that fail to deserialize are rejected with
+/// [`ToolError::MissingParameter`], [`ToolError::InvalidType`], or
+/// [`ToolError::InvalidValue`], naming the offending field. The output is
+/// serialized to JSON and returned like the value of [`Tool::call`].
+#[async_trait]
+pub trait TypedTool: Send + Sync {
+    /// The tool's arguments.
+    type Args: DeserializeOwned + JsonSchema + Send;
+    /// The tool's result.
+    type Output: Serialize + Send;
+
+    /// Get the tool's description.
+    ///
+    /// See [`Tool::description`].
+    fn description(&self) -> &str;
+
+    /// Get the JSON Schema of the tool's structured output, if it declares one.
+    ///
+    /// See [`Tool::output_schema`].
+    fn output_schema(&self) -> Option<Value> {
+        None
+    }
+
+    /// Get hints about the tool's behavior, if it declares any.
+    ///
+    /// See [`Tool::annotations`].
+    fn annotations(&self) -> Option<ToolAnnotations> {
+        None
+    }
+
+    /// Call the tool with its deserialized arguments.
+    async fn call(&self, args: Self::Args, ctx: &RequestContext)
+        -> Result<Self::Output, ToolError>;
+}
+
+#[async_trait]
+impl<T: TypedTool> Tool for T {
+    fn description(&self) -> &str {
+        TypedTool::description(self)
+    }
+
+    fn schema(&self) -> Value {
+        args_schema::<T::Args>()
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        TypedTool::output_schema(self)
+    }
+
+    fn annotations(&self) -> Option<ToolAnnotations> {
+        TypedTool::annotations(self)
+    }
+
+    async fn call_with_context(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        Ok(call_typed(self, arguments, ctx).await?)
+    }
+
+    async fn invoke(
+        &self,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        let value = call_typed(self, arguments, ctx).await?;
+        Ok(if TypedTool::output_schema(self).is_some() {
+            ToolResult::structured(value)
+        } else {
+            ToolResult::from(value)
+        })
+    }
+}
+
+async fn call_typed<T: TypedTool + ?Sized>(
+    tool: &T,
+    arguments: &Value,
+    ctx: &RequestContext,
+) -> Result<Value, ToolError> {
+    let args = parse_args::<T::Args>(arguments)?;
+    let output = tool.call(args, ctx).await?;
+    serde_json::to_value(output)
+        .map_err(|e| ToolError::execution_failed(format!("Failed to serialize output: {}", e)))
+}
+
+/// Generate the Draft 7 schema of a tool's arguments.
+fn args_schema<A: JsonSchema>() -> Value {
+    let generator = SchemaSettings::draft07()
+        .with(|settings| settings.meta_schema = None)
+        .into_generator();
+    serde_json::json!(generator.into_root_schema_for::<A>())
+}
+
+/// Deserialize tool arguments, naming the field at fault on failure.
+fn parse_args<A: DeserializeOwned>(arguments: &Value) -> Result<A, ToolError> {
+    serde_path_to_error::deserialize(arguments).map_err(|err| {
+        let path = err.path().to_string();
+        arg_error(&path, &err.into_inner().to_string())
+    })
+}
+
+/// Deserialize the argument `name` of a tool call.
+///
+/// A missing argument is treated as `null`, so `Option` parameters become
+/// `None`; for other types it is a [`ToolError::MissingParameter`].
+#[doc(hidden)]
+pub fn parse_arg<A: DeserializeOwned>(arguments: &Value, name: &str) -> Result<A, ToolError> {
+    let value = arguments.get(name).unwrap_or(&Value::Null);
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        if value.is_null() && arguments.get(name).is_none() {
+            return ToolError::missing_parameter(name);
+        }
+        let path = match err.path().to_string() {
+            path if path == "." => name.to_string(),
+            path => format!("{}.{}", name, path),
+        };
+        arg_error(&path, &err.into_inner().to_string())
+    })
+}
+
+/// Map a serde error message at `path` to the matching [`ToolError`].
+fn arg_error(path: &str, message: &str) -> ToolError {
+    // A path of "." is the arguments object itself
+    let param = |name: &str| match (path, name) {
+        (".", "") => "arguments".to_string(),
+        (".", name) => name.to_string(),
+        (path, "") => path.to_string(),
+        (path, name) => format!("{}.{}", path, name),
+    };
+
+    if let Some(rest) = message.strip_prefix("missing field `") {
+        if let Some(field) = rest.split('`').next() {
+            return ToolError::missing_parameter(param(field));
+        }
+    }
+    if let Some(rest) = message.strip_prefix("invalid type: ") {
+        if let Some((got, expected)) = rest.split_once(", expected ") {
+            return ToolError::invalid_type(param(""), expected, got);
+        }
+    }
+    ToolError::invalid_value(param(""), message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_field() {
+        assert_eq!(
+            arg_error(".", "missing field `query`"),
+            ToolError::missing_parameter("query")
+        );
+        assert_eq!(
+            arg_error("filter", "missing field `field`"),
+            ToolError::missing_parameter("filter.field")
+        );
+    }
+
+    #[test]
+    fn test_invalid_type() {
+        assert_eq!(
+            arg_error("limit", "invalid type: string \"ten\", expected u32"),
+            ToolError::invalid_type("limit", "u32", "string \"ten\"")
+        );
+        assert_eq!(
+            arg_error(".", "invalid type: string \"x\", expected struct Args"),
+            ToolError::invalid_type("arguments", "struct Args", "string \"x\"")
+        );
+    }
+
+    #[test]
+    fn test_other_errors_are_invalid_values() {
+        assert_eq!(
+            arg_error("mode", "unknown variant `fast`, expected `slow`"),
+            ToolError::invalid_value("mode", "unknown variant `fast`, expected `slow`")
+        );
+    }
+}
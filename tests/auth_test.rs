@@ -0,0 +1,195 @@
+//! Tests for built-in authentication (`McpServer::with_authenticator`).
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{request::Parts, Request, StatusCode},
+};
+use axum_mcp::auth::{AuthError, Authenticator};
+use axum_mcp::{auth::ApiKeyAuthenticator, Principal};
+use common::{app, call_whoami, caller, json_body};
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+fn api_keys() -> ApiKeyAuthenticator {
+    let hashed = ApiKeyAuthenticator::hash_key("bot-key");
+    ApiKeyAuthenticator::new()
+        .with_key(Principal::new("alice").with_scopes(["admin"]), "alice-key")
+        .with_key_hash("ci-bot", &hashed)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_valid_keys_authenticate() {
+    let app = app(api_keys());
+
+    let response = call_whoami(&app, &[("authorization", "Bearer alice-key")]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let seen = caller(&json_body(response).await);
+    assert_eq!(seen["id"], "alice");
+    assert_eq!(seen["scopes"], json!(["admin"]));
+
+    // Keys loaded by hash work the same, also through X-API-Key
+    let response = call_whoami(&app, &[("x-api-key", "bot-key")]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(caller(&json_body(response).await)["id"], "ci-bot");
+}
+
+#[tokio::test]
+async fn test_missing_credentials_challenged() {
+    let app = app(api_keys());
+
+    let response = call_whoami(&app, &[]).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers()["www-authenticate"],
+        "Bearer realm=\"mcp\""
+    );
+    let body = json_body(response).await;
+    assert_eq!(body["code"], 401);
+}
+
+#[tokio::test]
+async fn test_invalid_key_rejected() {
+    let app = app(api_keys().with_realm("internal"));
+
+    let response = call_whoami(&app, &[("authorization", "Bearer wrong-key")]).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenge = response.headers()["www-authenticate"].to_str().unwrap();
+    assert!(challenge.starts_with("Bearer realm=\"internal\""));
+    assert!(challenge.contains("error=\"invalid_token\""));
+}
+
+#[tokio::test]
+async fn test_all_mcp_routes_protected_but_health() {
+    let app = app(api_keys());
+
+    for (method, uri) in [
+        ("GET", "/tools/list"),
+        ("GET", "/resources/list"),
+        ("GET", "/prompts/list"),
+        ("POST", "/mcp"),
+    ] {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from("{}"))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", uri);
+    }
+
+    let request = Request::builder()
+        .uri("/health")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_jsonrpc_sees_principal() {
+    let app = app(api_keys());
+    let post = |session: Option<String>, body: Value| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("content-type", "application/json")
+            .header("accept", "application/json")
+            .header("authorization", "Bearer alice-key");
+        if let Some(session) = session {
+            request = request.header("mcp-session-id", session);
+        }
+        app.clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+    };
+
+    let response = post(
+        None,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+        }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let session = response.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let response = post(
+        Some(session),
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "whoami", "arguments": {} }
+        }),
+    )
+    .await
+    .unwrap();
+    let body = json_body(response).await;
+    assert_eq!(caller(&body["result"])["id"], "alice");
+}
+
+/// Accepts requests whose `x-user` header names a user, challenging with its
+/// own scheme.
+struct HeaderAuthenticator;
+
+#[async_trait]
+impl Authenticator for HeaderAuthenticator {
+    async fn authenticate(&self, request: &Parts) -> Result<Principal, AuthError> {
+        let user = request
+            .headers
+            .get("x-user")
+            .ok_or(AuthError::MissingCredentials)?;
+        let user = user
+            .to_str()
+            .map_err(|_| AuthError::invalid_credentials("Malformed user"))?;
+        Ok(Principal::new(user))
+    }
+
+    fn challenge(&self, _error: &AuthError) -> String {
+        "X-User".to_string()
+    }
+}
+
+#[tokio::test]
+async fn test_custom_authenticator() {
+    let app = app(HeaderAuthenticator);
+
+    let response = call_whoami(&app, &[("x-user", "bob")]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(caller(&json_body(response).await)["id"], "bob");
+
+    let response = call_whoami(&app, &[]).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()["www-authenticate"], "X-User");
+}
+
+#[test]
+fn test_key_file() {
+    let path = std::env::temp_dir().join(format!("axum-mcp-keys-{}", std::process::id()));
+    std::fs::write(
+        &path,
+        format!(
+            "# test keys\nci-bot {} tools:read\n",
+            ApiKeyAuthenticator::hash_key("bot-key")
+        ),
+    )
+    .unwrap();
+    let auth = ApiKeyAuthenticator::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(auth.len(), 1);
+    // The plaintext key never appears in debug output
+    assert!(!format!("{:?}", auth).contains("bot-key"));
+
+    assert!(ApiKeyAuthenticator::from_file("/nonexistent/axum-mcp-keys").is_err());
+}
@@ -0,0 +1,238 @@
+//! Tests for cancelling in-flight tool calls.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use axum_mcp::{transport::stdio, McpServer, RequestContext, ServerConfig, Tool};
+use common::initialize;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::Notify;
+use tower::util::ServiceExt;
+
+/// Blocks until cancelled, recording what it observed.
+#[derive(Clone, Default)]
+struct BlockingTool {
+    started: Arc<Notify>,
+    token_cancelled: Arc<AtomicBool>,
+    cleanup_ran: Arc<Notify>,
+}
+
+#[async_trait]
+impl Tool for BlockingTool {
+    fn description(&self) -> &str {
+        "Waits forever"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        // Cleanup runs outside the call future, which is dropped on cancellation
+        let token = ctx.cancellation_token().clone();
+        let token_cancelled = Arc::clone(&self.token_cancelled);
+        let cleanup_ran = Arc::clone(&self.cleanup_ran);
+        tokio::spawn(async move {
+            token.cancelled().await;
+            token_cancelled.store(true, Ordering::SeqCst);
+            cleanup_ran.notify_one();
+        });
+
+        self.started.notify_one();
+        std::future::pending::<()>().await;
+        Ok(json!({}))
+    }
+}
+
+async fn wait(notify: &Notify) {
+    tokio::time::timeout(Duration::from_secs(5), notify.notified())
+        .await
+        .expect("timed out");
+}
+
+fn initialize_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 0,
+        "method": "initialize",
+        "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+    })
+}
+
+fn call_request(id: i64) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": { "name": "block", "arguments": {} }
+    })
+}
+
+fn cancel_notification(id: i64) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/cancelled",
+        "params": { "requestId": id, "reason": "user gave up" }
+    })
+}
+
+#[tokio::test]
+async fn test_cancel_over_stdio() {
+    let tool = BlockingTool::default();
+    let server = McpServer::new().tool("block", tool.clone()).unwrap();
+    let (mut input, server_input) = tokio::io::duplex(64 * 1024);
+    let (server_output, output) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(stdio::serve_io(server, server_input, server_output));
+    let mut lines = BufReader::new(output).lines();
+
+    input
+        .write_all(format!("{}\n", initialize_request()).as_bytes())
+        .await
+        .unwrap();
+    lines.next_line().await.unwrap().unwrap();
+
+    input
+        .write_all(format!("{}\n", call_request(1)).as_bytes())
+        .await
+        .unwrap();
+    wait(&tool.started).await;
+    input
+        .write_all(format!("{}\n", cancel_notification(1)).as_bytes())
+        .await
+        .unwrap();
+    wait(&tool.cleanup_ran).await;
+    assert!(tool.token_cancelled.load(Ordering::SeqCst));
+
+    // The cancelled call is never answered; the next response is the ping's
+    input
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"ping\"}\n")
+        .await
+        .unwrap();
+    let response: Value = serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+    assert_eq!(response["id"], 2);
+}
+
+fn post(session: &str, accept: &str, body: Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("accept", accept)
+        .header("mcp-session-id", session)
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_cancel_notification_over_http() {
+    let tool = BlockingTool::default();
+    let app = McpServer::new()
+        .tool("block", tool.clone())
+        .unwrap()
+        .router();
+    let session = initialize(&app).await;
+
+    let call = tokio::spawn(app.clone().oneshot(post(
+        &session,
+        "application/json",
+        call_request(1),
+    )));
+    wait(&tool.started).await;
+
+    let response = app
+        .clone()
+        .oneshot(post(&session, "application/json", cancel_notification(1)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    wait(&tool.cleanup_ran).await;
+    let response = tokio::time::timeout(Duration::from_secs(5), call)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_sse_disconnect_cancels_call() {
+    let tool = BlockingTool::default();
+    let app = McpServer::new()
+        .tool("block", tool.clone())
+        .unwrap()
+        .router();
+    let session = initialize(&app).await;
+
+    let response = app
+        .clone()
+        .oneshot(post(&session, "text/event-stream", call_request(1)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    wait(&tool.started).await;
+
+    // Dropping the body is what hyper does when the client goes away
+    drop(response);
+    wait(&tool.cleanup_ran).await;
+    assert!(tool.token_cancelled.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_timeout_cancels_token() {
+    let tool = BlockingTool::default();
+    let app =
+        McpServer::with_config(ServerConfig::new().with_tool_timeout(Duration::from_millis(50)))
+            .tool("block", tool.clone())
+            .unwrap()
+            .router();
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "name": "block" }).to_string()))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    wait(&tool.cleanup_ran).await;
+}
+
+#[tokio::test]
+async fn test_cancel_unknown_request_is_ignored() {
+    let app = McpServer::new()
+        .tool("block", BlockingTool::default())
+        .unwrap()
+        .router();
+    let session = initialize(&app).await;
+
+    let response = app
+        .clone()
+        .oneshot(post(&session, "application/json", cancel_notification(99)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let response = app
+        .clone()
+        .oneshot(post(
+            &session,
+            "application/json",
+            json!({"jsonrpc": "2.0", "id": 3, "method": "ping"}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
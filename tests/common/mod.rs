@@ -0,0 +1,123 @@
+//! Helpers shared by the integration tests.
+
+// Each test crate uses a different subset of the helpers
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+    Router,
+};
+use axum_mcp::auth::Authenticator;
+use axum_mcp::{McpServer, RequestContext, Tool};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+/// POST `body` as JSON to `uri`, within `session` if given, and return the
+/// status and JSON response.
+pub async fn send(
+    app: &Router,
+    uri: &str,
+    body: Value,
+    session: Option<&str>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("accept", "application/json");
+    if let Some(session) = session {
+        request = request.header("mcp-session-id", session);
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, json_body(response).await)
+}
+
+/// Complete the `initialize` handshake on `/mcp` and return the session id.
+pub async fn initialize(app: &Router) -> String {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("accept", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    response.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Returns the id and scopes of the authenticated caller.
+pub struct WhoAmITool;
+
+#[async_trait]
+impl Tool for WhoAmITool {
+    fn description(&self) -> &str {
+        "Describe the caller"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        let principal = ctx.principal().ok_or("Not authenticated")?;
+        Ok(json!({ "id": principal.id, "scopes": principal.scopes }))
+    }
+}
+
+/// A server exposing [`WhoAmITool`] as `whoami`, behind `authenticator`.
+pub fn app(authenticator: impl Authenticator + 'static) -> Router {
+    McpServer::new()
+        .tool("whoami", WhoAmITool)
+        .unwrap()
+        .with_authenticator(authenticator)
+        .router()
+}
+
+/// Call `whoami` over REST with the given extra headers.
+pub async fn call_whoami(app: &Router, headers: &[(&str, &str)]) -> Response {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let body = json!({ "name": "whoami", "arguments": {} }).to_string();
+    app.clone()
+        .oneshot(request.body(Body::from(body)).unwrap())
+        .await
+        .unwrap()
+}
+
+pub async fn json_body(response: Response) -> Value {
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+/// The caller as reported by a successful `whoami` call.
+pub fn caller(body: &Value) -> Value {
+    serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap()
+}
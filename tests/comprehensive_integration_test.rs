@@ -330,7 +330,7 @@ async fn test_configuration_affects_behavior() {
         "Timeout should occur around 1 second, but took {:?}",
         elapsed
     );
-    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
 }
 
 struct SlowTool;
@@ -0,0 +1,259 @@
+//! Tests for closure-based tools, resources, and prompts.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{FnTool, McpServer, RequestContext, ToolError};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tower::util::ServiceExt;
+
+type Store = Arc<Mutex<HashMap<String, String>>>;
+
+fn server(store: &Store) -> McpServer {
+    let set_store = Arc::clone(store);
+    let get_store = Arc::clone(store);
+    let count_store = Arc::clone(store);
+    McpServer::new()
+        .tool_fn(
+            "set",
+            "Store a value",
+            json!({
+                "type": "object",
+                "properties": { "key": { "type": "string" }, "value": { "type": "string" } },
+                "required": ["key", "value"]
+            }),
+            move |args: Value| {
+                let store = Arc::clone(&set_store);
+                async move {
+                    let key = args["key"].as_str().unwrap_or_default().to_string();
+                    let value = args["value"].as_str().unwrap_or_default().to_string();
+                    store.lock().unwrap().insert(key, value);
+                    Ok(json!({ "stored": true }))
+                }
+            },
+        )
+        .unwrap()
+        .tool_fn(
+            "get",
+            "Look up a value",
+            json!({
+                "type": "object",
+                "properties": { "key": { "type": "string" } },
+                "required": ["key"]
+            }),
+            move |args: Value| {
+                let store = Arc::clone(&get_store);
+                async move {
+                    let key = args["key"].as_str().unwrap_or_default();
+                    let value = store.lock().unwrap().get(key).cloned();
+                    match value {
+                        Some(value) => Ok(json!(value)),
+                        None => Err(ToolError::invalid_value("key", "no such key")),
+                    }
+                }
+            },
+        )
+        .unwrap()
+        .resource_fn(
+            "kv://count",
+            "Key count",
+            "Number of stored keys",
+            "text/plain",
+            move || {
+                let store = Arc::clone(&count_store);
+                async move { Ok(store.lock().unwrap().len().to_string()) }
+            },
+        )
+        .unwrap()
+        .prompt_fn(
+            "summarize",
+            "Summarize a topic",
+            json!([{ "name": "topic", "description": "Topic", "required": true }]),
+            |args: Value| async move {
+                match args["topic"].as_str() {
+                    Some(topic) => Ok(format!("Summarize {} in one paragraph.", topic)),
+                    None => Err("Missing 'topic'".to_string()),
+                }
+            },
+        )
+        .unwrap()
+}
+
+async fn post(app: &Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_tools_share_captured_state() {
+    let store = Store::default();
+    let app = server(&store).router();
+
+    let (status, _) = post(
+        &app,
+        "/tools/call",
+        json!({ "name": "set", "arguments": { "key": "color", "value": "blue" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(store.lock().unwrap()["color"], "blue");
+
+    let (status, body) = post(
+        &app,
+        "/tools/call",
+        json!({ "name": "get", "arguments": { "key": "color" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"][0]["text"], "\"blue\"");
+
+    let (status, body) = post(
+        &app,
+        "/tools/call",
+        json!({ "name": "get", "arguments": { "key": "size" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "invalid_value");
+}
+
+#[tokio::test]
+async fn test_resource_fn() {
+    let store = Store::default();
+    store
+        .lock()
+        .unwrap()
+        .insert("a".to_string(), "1".to_string());
+    let app = server(&store).router();
+
+    let (status, body) = post(&app, "/resources/read", json!({ "uri": "kv://count" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "1");
+    assert_eq!(body["contents"][0]["mimeType"], "text/plain");
+}
+
+#[tokio::test]
+async fn test_prompt_fn() {
+    let app = server(&Store::default()).router();
+    let (status, body) = post(
+        &app,
+        "/prompts/get",
+        json!({ "name": "summarize", "arguments": { "topic": "Rust" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["messages"][0]["content"]["text"],
+        "Summarize Rust in one paragraph."
+    );
+}
+
+#[tokio::test]
+async fn test_fn_tool_added_at_runtime() {
+    let server = McpServer::new();
+    let handle = server.handle();
+    let app = server.router();
+
+    handle
+        .add_tool(
+            "ping",
+            FnTool::new("Reply with pong", json!({ "type": "object" }), |_| async {
+                Ok(json!("pong"))
+            }),
+        )
+        .unwrap();
+
+    let (status, body) = post(&app, "/tools/call", json!({ "name": "ping" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"][0]["text"], "\"pong\"");
+}
+
+#[tokio::test]
+async fn test_fn_handlers_with_context() {
+    let request_id = |ctx: &RequestContext| ctx.request_id().unwrap_or_default().to_string();
+    let app = McpServer::new()
+        .tool_fn_with_context(
+            "request_id",
+            "Report the request id",
+            json!({ "type": "object" }),
+            move |_args: Value, ctx: RequestContext| async move { Ok(json!(request_id(&ctx))) },
+        )
+        .unwrap()
+        .resource_fn_with_context(
+            "req://id",
+            "Request id",
+            "The request id",
+            "text/plain",
+            move |ctx: RequestContext| async move { Ok(request_id(&ctx)) },
+        )
+        .unwrap()
+        .prompt_fn_with_context(
+            "tag",
+            "Tag the request",
+            json!([]),
+            move |_args: Value, ctx: RequestContext| async move {
+                Ok(format!("Request {}", request_id(&ctx)))
+            },
+        )
+        .unwrap()
+        .router();
+
+    let send = |uri: &str, body: Value| {
+        let request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("x-request-id", "req-42")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        }
+    };
+
+    let body = send("/tools/call", json!({ "name": "request_id" })).await;
+    assert_eq!(body["content"][0]["text"], "\"req-42\"");
+    let body = send("/resources/read", json!({ "uri": "req://id" })).await;
+    assert_eq!(body["contents"][0]["text"], "req-42");
+    let body = send("/prompts/get", json!({ "name": "tag" })).await;
+    assert_eq!(body["messages"][0]["content"]["text"], "Request req-42");
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_context_tool_outside_server() {
+    use axum_mcp::{test_tool, Tool};
+
+    let tool = FnTool::with_context(
+        "Report whether the request was cancelled",
+        json!({ "type": "object" }),
+        |args: Value, ctx: RequestContext| async move {
+            if args.get("fail").is_some() {
+                return Err(ToolError::execution_failed("asked to fail"));
+            }
+            Ok(json!({ "cancelled": ctx.is_cancelled() }))
+        },
+    );
+
+    let result = test_tool(&tool, json!({})).await.unwrap();
+    assert_eq!(result, json!({ "cancelled": false }));
+    assert_eq!(tool.call(&json!({})).await.unwrap(), result);
+    let err = test_tool(&tool, json!({ "fail": true })).await.unwrap_err();
+    assert!(err.contains("asked to fail"), "{}", err);
+}
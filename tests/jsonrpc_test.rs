@@ -0,0 +1,358 @@
+//! Tests for the JSON-RPC 2.0 MCP endpoint.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{extract_string, McpServer, Prompt, Resource, ServerConfig, Tool};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+struct EchoTool;
+
+#[async_trait]
+impl Tool for EchoTool {
+    fn description(&self) -> &str {
+        "Echo back the input text"
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" }
+            },
+            "required": ["text"]
+        })
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        let text = extract_string(arguments, "text")?;
+        Ok(json!({ "echoed": text }))
+    }
+}
+
+struct TestResource;
+
+#[async_trait]
+impl Resource for TestResource {
+    fn name(&self) -> &str {
+        "Test Resource"
+    }
+
+    fn description(&self) -> &str {
+        "A test resource"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok("test content".to_string())
+    }
+}
+
+struct TestPrompt;
+
+#[async_trait]
+impl Prompt for TestPrompt {
+    fn description(&self) -> &str {
+        "A test prompt"
+    }
+
+    fn arguments(&self) -> Value {
+        json!([{ "name": "name", "required": false }])
+    }
+
+    async fn render(&self, arguments: &Value) -> Result<String, String> {
+        let name = arguments
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("World");
+        Ok(format!("Hello, {}!", name))
+    }
+}
+
+/// A router with an initialized MCP session.
+struct Client {
+    app: Router,
+    path: &'static str,
+    session_id: String,
+}
+
+impl Client {
+    /// Complete the `initialize` handshake and remember the session id.
+    async fn connect(app: Router, path: &'static str) -> Self {
+        let message = json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-06-18",
+                "capabilities": {},
+                "clientInfo": { "name": "test", "version": "0.0.0" }
+            }
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(path)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&message).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let session_id = response.headers()["mcp-session-id"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        Self {
+            app,
+            path,
+            session_id,
+        }
+    }
+
+    async fn post(&self, body: impl Into<Body>) -> (StatusCode, Vec<u8>) {
+        let response = self
+            .app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(self.path)
+                    .header("content-type", "application/json")
+                    .header("mcp-session-id", &self.session_id)
+                    .body(body.into())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, body.to_vec())
+    }
+
+    async fn rpc(&self, message: Value) -> Value {
+        let (status, body) = self.post(serde_json::to_vec(&message).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        serde_json::from_slice(&body).unwrap()
+    }
+}
+
+async fn app() -> Client {
+    let app = McpServer::new()
+        .tool("echo", EchoTool)
+        .unwrap()
+        .resource("test://resource", TestResource)
+        .unwrap()
+        .prompt("greeting", TestPrompt)
+        .unwrap()
+        .router();
+    Client::connect(app, "/mcp").await
+}
+
+#[tokio::test]
+async fn test_tools_list() {
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"}))
+        .await;
+    assert_eq!(response["jsonrpc"], "2.0");
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["tools"][0]["name"], "echo");
+    assert!(response.get("error").is_none());
+}
+
+#[tokio::test]
+async fn test_tools_call() {
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": "call-1",
+            "method": "tools/call",
+            "params": { "name": "echo", "arguments": { "text": "hello" } }
+        }))
+        .await;
+    assert_eq!(response["id"], "call-1");
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let content: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(content["echoed"], "hello");
+}
+
+#[tokio::test]
+async fn test_resources_and_prompts() {
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "2.0", "id": 1, "method": "resources/list"}))
+        .await;
+    assert_eq!(response["result"]["resources"][0]["uri"], "test://resource");
+
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "resources/read",
+            "params": { "uri": "test://resource" }
+        }))
+        .await;
+    assert_eq!(response["result"]["contents"][0]["text"], "test content");
+
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "2.0", "id": 3, "method": "prompts/list"}))
+        .await;
+    assert_eq!(response["result"]["prompts"][0]["name"], "greeting");
+
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 4,
+            "method": "prompts/get",
+            "params": { "name": "greeting", "arguments": { "name": "Ada" } }
+        }))
+        .await;
+    assert_eq!(
+        response["result"]["messages"][0]["content"]["text"],
+        "Hello, Ada!"
+    );
+}
+
+#[tokio::test]
+async fn test_method_not_found() {
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "2.0", "id": 1, "method": "tools/unknown"}))
+        .await;
+    assert_eq!(response["error"]["code"], -32601);
+    assert!(response.get("result").is_none());
+}
+
+#[tokio::test]
+async fn test_invalid_params() {
+    // Unknown tool
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "missing" }
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    // Schema validation failure
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "echo", "arguments": {} }
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    // Params must be an object
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "2.0", "id": 3, "method": "tools/call", "params": [1, 2]}))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn test_resource_not_found() {
+    let response = app()
+        .await
+        .rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "resources/read",
+            "params": { "uri": "test://missing" }
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32002);
+}
+
+#[tokio::test]
+async fn test_invalid_request() {
+    let response = app()
+        .await
+        .rpc(json!({"jsonrpc": "1.0", "id": 7, "method": "ping"}))
+        .await;
+    assert_eq!(response["error"]["code"], -32600);
+    assert_eq!(response["id"], 7);
+
+    let response = app().await.rpc(json!({"jsonrpc": "2.0", "id": 8})).await;
+    assert_eq!(response["error"]["code"], -32600);
+
+    let response = app()
+        .await
+        .rpc(json!([{"jsonrpc": "2.0", "id": 9, "method": "ping"}]))
+        .await;
+    assert_eq!(response["error"]["code"], -32600);
+    assert!(response["id"].is_null());
+}
+
+#[tokio::test]
+async fn test_parse_error() {
+    let (status, body) = app().await.post("{not json").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let response: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response["error"]["code"], -32700);
+    assert!(response["id"].is_null());
+}
+
+#[tokio::test]
+async fn test_notification_accepted() {
+    let message = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+    let (status, body) = app()
+        .await
+        .post(serde_json::to_vec(&message).unwrap())
+        .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_custom_path() {
+    let app = McpServer::with_config(ServerConfig::new().with_mcp_path("/rpc"))
+        .tool("echo", EchoTool)
+        .unwrap()
+        .router();
+
+    let client = Client::connect(app, "/rpc").await;
+    let response = client
+        .rpc(json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"}))
+        .await;
+    assert_eq!(response["result"]["tools"][0]["name"], "echo");
+}
+
+#[tokio::test]
+async fn test_rest_routes_still_available() {
+    let response = app()
+        .await
+        .app
+        .oneshot(
+            Request::builder()
+                .uri("/tools/list")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
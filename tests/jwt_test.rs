@@ -0,0 +1,321 @@
+//! Tests for JWT bearer authentication (`jwt` feature), using a keypair
+//! generated for each test run.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+    routing::get,
+    Router,
+};
+use axum_mcp::auth::JwtAuthenticator;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use common::{app, caller, json_body};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tower::util::ServiceExt;
+
+const ISSUER: &str = "https://auth.example.com";
+const AUDIENCE: &str = "https://mcp.example.com/mcp";
+const METADATA_URL: &str = "https://mcp.example.com/.well-known/oauth-protected-resource";
+
+/// A signing key and the JWK that verifies it.
+struct TestKey {
+    kid: &'static str,
+    encoding: EncodingKey,
+    jwk: Value,
+}
+
+impl TestKey {
+    fn generate(kid: &'static str) -> Self {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        Self {
+            kid,
+            encoding: EncodingKey::from_ed_der(pkcs8.as_ref()),
+            jwk: json!({
+                "kty": "OKP",
+                "crv": "Ed25519",
+                "x": URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+                "kid": kid,
+                "alg": "EdDSA",
+                "use": "sig"
+            }),
+        }
+    }
+
+    fn sign(&self, claims: &Value) -> String {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(self.kid.to_string());
+        jsonwebtoken::encode(&header, claims, &self.encoding).unwrap()
+    }
+}
+
+fn jwks(keys: &[&TestKey]) -> Value {
+    json!({ "keys": keys.iter().map(|key| key.jwk.clone()).collect::<Vec<_>>() })
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn claims() -> Value {
+    json!({
+        "iss": ISSUER,
+        "aud": AUDIENCE,
+        "sub": "alice",
+        "exp": now() + 300,
+        "scope": "tools:read tools:call"
+    })
+}
+
+fn with_claim(name: &str, value: Value) -> Value {
+    let mut claims = claims();
+    claims[name] = value;
+    claims
+}
+
+fn authenticator(key: &TestKey) -> JwtAuthenticator {
+    JwtAuthenticator::new(ISSUER, AUDIENCE)
+        .with_jwks_json(&jwks(&[key]).to_string())
+        .unwrap()
+        .with_scopes_supported(["tools:read", "tools:call"])
+}
+
+/// Call `whoami` with `token` as the bearer token, if any.
+async fn call_whoami(app: &Router, token: Option<&str>) -> Response {
+    match token {
+        Some(token) => {
+            let authorization = format!("Bearer {}", token);
+            common::call_whoami(app, &[("authorization", &authorization)]).await
+        }
+        None => common::call_whoami(app, &[]).await,
+    }
+}
+
+fn challenge(response: &Response) -> &str {
+    response.headers()["www-authenticate"].to_str().unwrap()
+}
+
+#[tokio::test]
+async fn test_valid_token_maps_scopes() {
+    let key = TestKey::generate("k1");
+    let app = app(authenticator(&key));
+
+    let response = call_whoami(&app, Some(&key.sign(&claims()))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let seen = caller(&json_body(response).await);
+    assert_eq!(seen["id"], "alice");
+    assert_eq!(seen["scopes"], json!(["tools:read", "tools:call"]));
+
+    // `scp` arrays and a single audience among several work too
+    let mut claims = with_claim("aud", json!(["other", AUDIENCE]));
+    claims["scp"] = json!(["admin"]);
+    let response = call_whoami(&app, Some(&key.sign(&claims))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        caller(&json_body(response).await)["scopes"],
+        json!(["tools:read", "tools:call", "admin"])
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_tokens_rejected() {
+    let key = TestKey::generate("k1");
+    let other = TestKey::generate("k1");
+    let app = app(authenticator(&key));
+
+    let cases = [
+        ("expired", key.sign(&with_claim("exp", json!(now() - 600)))),
+        (
+            "not yet valid",
+            key.sign(&with_claim("nbf", json!(now() + 600))),
+        ),
+        (
+            "wrong issuer",
+            key.sign(&with_claim("iss", json!("https://evil.example.com"))),
+        ),
+        (
+            "wrong audience",
+            key.sign(&with_claim("aud", json!("https://other.example.com"))),
+        ),
+        ("wrong key", other.sign(&claims())),
+        ("unknown kid", TestKey::generate("k2").sign(&claims())),
+        ("malformed", "not-a-jwt".to_string()),
+    ];
+    for (case, token) in cases {
+        let response = call_whoami(&app, Some(&token)).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", case);
+        let challenge = challenge(&response);
+        assert!(challenge.contains("error=\"invalid_token\""), "{}", case);
+        assert!(challenge.contains(METADATA_URL), "{}", case);
+    }
+
+    // A claim the resource server requires is missing
+    let mut claims = claims();
+    claims.as_object_mut().unwrap().remove("sub");
+    let response = call_whoami(&app, Some(&key.sign(&claims))).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_symmetric_algorithm_rejected() {
+    // A token MACed with the public key must not pass as signed by it
+    let key = TestKey::generate("k1");
+    let public = URL_SAFE_NO_PAD
+        .decode(key.jwk["x"].as_str().unwrap())
+        .unwrap();
+    let mut header = Header::new(Algorithm::HS256);
+    header.kid = Some("k1".to_string());
+    let token =
+        jsonwebtoken::encode(&header, &claims(), &EncodingKey::from_secret(&public)).unwrap();
+
+    let response = call_whoami(&app(authenticator(&key)), Some(&token)).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_missing_token_challenged_with_metadata() {
+    let key = TestKey::generate("k1");
+    let response = call_whoami(&app(authenticator(&key)), None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        challenge(&response),
+        format!(
+            "Bearer realm=\"mcp\", resource_metadata=\"{}\"",
+            METADATA_URL
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_protected_resource_metadata_is_public() {
+    let key = TestKey::generate("k1");
+    let request = Request::builder()
+        .uri("/.well-known/oauth-protected-resource")
+        .body(Body::empty())
+        .unwrap();
+    let response = app(authenticator(&key)).oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        json_body(response).await,
+        json!({
+            "resource": AUDIENCE,
+            "authorization_servers": [ISSUER],
+            "scopes_supported": ["tools:read", "tools:call"],
+            "bearer_methods_supported": ["header"]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_jwks_file() {
+    let key = TestKey::generate("k1");
+    let path = std::env::temp_dir().join(format!("axum-mcp-jwks-{}.json", std::process::id()));
+    std::fs::write(&path, jwks(&[&key]).to_string()).unwrap();
+    let auth = JwtAuthenticator::new(ISSUER, AUDIENCE).with_jwks_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let response = call_whoami(&app(auth.unwrap()), Some(&key.sign(&claims()))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert!(JwtAuthenticator::new(ISSUER, AUDIENCE)
+        .with_jwks_json("{\"keys\": 1}")
+        .is_err());
+}
+
+/// Serve `jwks` over HTTP with `status`, counting fetches.
+async fn serve_jwks(status: StatusCode, jwks: Value) -> (String, Arc<AtomicUsize>) {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&fetches);
+    let app = Router::new().route(
+        "/jwks.json",
+        get(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let jwks = jwks.clone();
+            async move { (status, axum::Json(jwks)) }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (format!("http://{}/jwks.json", addr), fetches)
+}
+
+#[tokio::test]
+async fn test_jwks_url_fetched_and_cached() {
+    let key = TestKey::generate("k1");
+    let (url, fetches) = serve_jwks(StatusCode::OK, jwks(&[&key])).await;
+    let app = app(JwtAuthenticator::new(ISSUER, AUDIENCE).with_jwks_url(url));
+
+    // Concurrent requests share a single fetch
+    let token = key.sign(&claims());
+    let (a, b, c) = tokio::join!(
+        call_whoami(&app, Some(&token)),
+        call_whoami(&app, Some(&token)),
+        call_whoami(&app, Some(&token)),
+    );
+    for response in [a, b, c] {
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = call_whoami(&app, Some(&token)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    // An unknown kid was seen too recently to refetch
+    let response = call_whoami(&app, Some(&TestKey::generate("k2").sign(&claims()))).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_unreachable_jwks_url_unavailable() {
+    let key = TestKey::generate("k1");
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/jwks.json", listener.local_addr().unwrap());
+    drop(listener);
+
+    let app = app(JwtAuthenticator::new(ISSUER, AUDIENCE).with_jwks_url(url));
+    let response = call_whoami(&app, Some(&key.sign(&claims()))).await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(!response.headers().contains_key("www-authenticate"));
+}
+
+#[tokio::test]
+async fn test_failed_jwks_fetch_not_retried_immediately() {
+    let key = TestKey::generate("k1");
+    let (url, fetches) = serve_jwks(StatusCode::INTERNAL_SERVER_ERROR, json!({})).await;
+    let app = app(JwtAuthenticator::new(ISSUER, AUDIENCE).with_jwks_url(url));
+
+    for _ in 0..2 {
+        let response = call_whoami(&app, Some(&key.sign(&claims()))).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_jwks_refresh_in_any_builder_order() {
+    let key = TestKey::generate("k1");
+    let (url, fetches) = serve_jwks(StatusCode::OK, jwks(&[&key])).await;
+    let auth = JwtAuthenticator::new(ISSUER, AUDIENCE)
+        .with_jwks_refresh(Duration::ZERO)
+        .with_jwks_url(url);
+    let app = app(auth);
+
+    for _ in 0..2 {
+        let response = call_whoami(&app, Some(&key.sign(&claims()))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,168 @@
+//! Tests for the `initialize` handshake and capability negotiation.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{lifecycle::LATEST_PROTOCOL_VERSION, McpServer, Resource, ServerInfo, Tool};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+struct NoopTool;
+
+#[async_trait]
+impl Tool for NoopTool {
+    fn description(&self) -> &str {
+        "Does nothing"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!({}))
+    }
+}
+
+struct NoopResource;
+
+#[async_trait]
+impl Resource for NoopResource {
+    fn name(&self) -> &str {
+        "Noop"
+    }
+
+    fn description(&self) -> &str {
+        "Empty resource"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok(String::new())
+    }
+}
+
+async fn rpc(app: Router, message: Value) -> Value {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/mcp")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&message).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+fn initialize_request(protocol_version: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": protocol_version,
+            "capabilities": {},
+            "clientInfo": { "name": "test-client", "version": "1.0.0" }
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_initialize_reports_server_info() {
+    let app = McpServer::new()
+        .with_server_info(ServerInfo::new("weather", "2.1.0").with_instructions("Be brief"))
+        .router();
+
+    let response = rpc(app, initialize_request(LATEST_PROTOCOL_VERSION)).await;
+    let result = &response["result"];
+    assert_eq!(result["protocolVersion"], LATEST_PROTOCOL_VERSION);
+    assert_eq!(result["serverInfo"]["name"], "weather");
+    assert_eq!(result["serverInfo"]["version"], "2.1.0");
+    assert_eq!(result["instructions"], "Be brief");
+}
+
+#[tokio::test]
+async fn test_capabilities_reflect_registrations() {
+    let app = McpServer::new()
+        .tool("noop", NoopTool)
+        .unwrap()
+        .resource("test://noop", NoopResource)
+        .unwrap()
+        .router();
+
+    let response = rpc(app, initialize_request(LATEST_PROTOCOL_VERSION)).await;
+    let capabilities = &response["result"]["capabilities"];
+    assert!(capabilities["tools"].is_object());
+    assert!(capabilities["resources"].is_object());
+    assert!(capabilities.get("prompts").is_none());
+}
+
+#[tokio::test]
+async fn test_protocol_version_negotiation() {
+    let app = McpServer::new().router();
+    let response = rpc(app, initialize_request("2024-11-05")).await;
+    assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+
+    let app = McpServer::new().router();
+    let response = rpc(app, initialize_request("1999-01-01")).await;
+    assert_eq!(
+        response["result"]["protocolVersion"],
+        LATEST_PROTOCOL_VERSION
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_requires_protocol_version() {
+    let app = McpServer::new().router();
+    let response = rpc(
+        app,
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn test_requests_rejected_before_initialize() {
+    let app = McpServer::new().tool("noop", NoopTool).unwrap().router();
+
+    // Without an initialized session there is nothing to dispatch against
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/mcp")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"}).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn test_default_server_info() {
+    let server = McpServer::new();
+    assert_eq!(server.server_info().name, "axum-mcp");
+    assert_eq!(server.server_info().version, env!("CARGO_PKG_VERSION"));
+    assert!(server.server_info().instructions.is_none());
+}
@@ -0,0 +1,147 @@
+//! Tests for the `logging` capability and `notifications/message`.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{body::Body, http::Request, Router};
+use axum_mcp::{LoggingLevel, McpServer, RequestContext, Tool};
+use common::{initialize, send};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+/// Logs one message at each of `debug`, `warning` and `error`.
+struct ChattyTool;
+
+#[async_trait]
+impl Tool for ChattyTool {
+    fn description(&self) -> &str {
+        "Logs while it works"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        ctx.log(LoggingLevel::Debug, None, json!("starting"));
+        ctx.log(LoggingLevel::Warning, Some("db"), json!({ "slow": true }));
+        ctx.log(LoggingLevel::Error, Some("db"), json!("retrying"));
+        Ok(json!({ "ok": true }))
+    }
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .tool("chatty", ChattyTool)
+        .unwrap()
+        .router()
+}
+
+/// Call the tool with an SSE response and return the log messages sent before it.
+async fn call_logs(app: &Router, session: &str) -> Vec<Value> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "method": "tools/call",
+        "params": { "name": "chatty", "arguments": {} }
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("accept", "application/json, text/event-stream")
+        .header("mcp-session-id", session)
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(bytes.to_vec())
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .map(|data| serde_json::from_str::<Value>(data).unwrap())
+        .filter(|message| message["method"] == "notifications/message")
+        .map(|message| message["params"].clone())
+        .collect()
+}
+
+async fn set_level(app: &Router, session: &str, level: Value) -> Value {
+    let (_, body) = send(
+        app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "logging/setLevel",
+            "params": { "level": level }
+        }),
+        Some(session),
+    )
+    .await;
+    body
+}
+
+#[tokio::test]
+async fn test_logging_capability_advertised() {
+    let app = app();
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+        }),
+        None,
+    )
+    .await;
+    assert_eq!(body["result"]["capabilities"]["logging"], json!({}));
+}
+
+#[tokio::test]
+async fn test_all_messages_sent_without_level() {
+    let app = app();
+    let session = initialize(&app).await;
+
+    let logs = call_logs(&app, &session).await;
+    assert_eq!(logs.len(), 3);
+    assert_eq!(logs[0], json!({ "level": "debug", "data": "starting" }));
+    assert_eq!(
+        logs[1],
+        json!({ "level": "warning", "logger": "db", "data": { "slow": true } })
+    );
+}
+
+#[tokio::test]
+async fn test_set_level_filters_messages() {
+    let app = app();
+    let session = initialize(&app).await;
+
+    let response = set_level(&app, &session, json!("warning")).await;
+    assert_eq!(response["result"], json!({}));
+
+    let levels: Vec<Value> = call_logs(&app, &session)
+        .await
+        .into_iter()
+        .map(|log| log["level"].clone())
+        .collect();
+    assert_eq!(levels, [json!("warning"), json!("error")]);
+}
+
+#[tokio::test]
+async fn test_invalid_level_rejected() {
+    let app = app();
+    let session = initialize(&app).await;
+
+    let response = set_level(&app, &session, json!("verbose")).await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    let response = set_level(&app, &session, json!(3)).await;
+    assert_eq!(response["error"]["code"], -32602);
+}
@@ -0,0 +1,229 @@
+//! Tests for the `#[mcp_tool]`, `#[mcp_resource]`, and `#[mcp_prompt]` macros.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{mcp_prompt, mcp_resource, mcp_tool, McpServer, RequestContext, ToolError};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+/// Search the paper index.
+///
+/// # Arguments
+/// * `query` - Search terms (type: string)
+/// * `limit` - Maximum number of results
+#[mcp_tool]
+async fn search(query: String, limit: Option<u32>, tags: Vec<String>) -> Result<Value, String> {
+    if query.is_empty() {
+        return Err("Query must not be empty".to_string());
+    }
+    Ok(json!({ "query": query, "limit": limit.unwrap_or(10), "tags": tags }))
+}
+
+/// Divide two numbers.
+#[mcp_tool(name = "divide")]
+fn checked_divide(dividend: f64, divisor: f64, ctx: &RequestContext) -> Result<f64, ToolError> {
+    let _ = ctx;
+    if divisor == 0.0 {
+        return Err(ToolError::invalid_value("divisor", "must not be zero"));
+    }
+    Ok(dividend / divisor)
+}
+
+/// Status of the latest CI build.
+#[mcp_resource(uri = "ci://status", name = "Build status")]
+async fn build_status() -> Result<&'static str, String> {
+    Ok("passing")
+}
+
+/// Review code for bugs.
+///
+/// # Arguments
+/// * `code` - The code to review
+#[mcp_prompt(name = "code_review")]
+async fn review(code: String, focus: Option<String>) -> Result<String, String> {
+    Ok(format!(
+        "Review for {}:\n{}",
+        focus.as_deref().unwrap_or("bugs"),
+        code
+    ))
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .tool(SearchTool::NAME, SearchTool)
+        .unwrap()
+        .tool(CheckedDivideTool::NAME, CheckedDivideTool)
+        .unwrap()
+        .resource(BuildStatusResource::URI, BuildStatusResource)
+        .unwrap()
+        .prompt(ReviewPrompt::NAME, ReviewPrompt)
+        .unwrap()
+        .router()
+}
+
+async fn get(uri: &str) -> Value {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+async fn post(uri: &str, body: Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_tool_schema_from_signature() {
+    let body = get("/tools/list").await;
+    let tools = body["tools"].as_array().unwrap();
+    let search = tools.iter().find(|t| t["name"] == "search").unwrap();
+    assert_eq!(search["description"], "Search the paper index.");
+    assert_eq!(
+        search["inputSchema"],
+        json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "Search terms" },
+                "limit": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Maximum number of results"
+                },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            },
+            "required": ["query", "tags"]
+        })
+    );
+
+    // The context parameter is not part of the schema
+    let divide = tools.iter().find(|t| t["name"] == "divide").unwrap();
+    assert_eq!(
+        divide["inputSchema"]["required"],
+        json!(["dividend", "divisor"])
+    );
+}
+
+#[tokio::test]
+async fn test_tool_call() {
+    let (status, body) = post(
+        "/tools/call",
+        json!({ "name": "search", "arguments": { "query": "rust", "tags": ["async"] } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let text: Value = serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        text,
+        json!({ "query": "rust", "limit": 10, "tags": ["async"] })
+    );
+
+    let (status, body) = post(
+        "/tools/call",
+        json!({ "name": "search", "arguments": { "query": "", "tags": [] } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        body["message"],
+        "Tool execution failed: Query must not be empty"
+    );
+}
+
+#[tokio::test]
+async fn test_tool_errors() {
+    let (status, body) = post(
+        "/tools/call",
+        json!({ "name": "divide", "arguments": { "dividend": 1, "divisor": 0 } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "invalid_value");
+
+    let (status, body) = post(
+        "/tools/call",
+        json!({ "name": "divide", "arguments": { "dividend": 9, "divisor": 3 } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"][0]["text"], "3.0");
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_tool_outside_server() {
+    use axum_mcp::{test_tool, Tool};
+
+    let result = test_tool(&SearchTool, json!({ "query": "rust", "tags": [] })).await;
+    assert_eq!(
+        result.unwrap(),
+        json!({ "query": "rust", "limit": 10, "tags": [] })
+    );
+    let divide = CheckedDivideTool;
+    let args = json!({ "dividend": 9, "divisor": 3 });
+    assert_eq!(test_tool(&divide, args.clone()).await.unwrap(), 3.0);
+    assert_eq!(divide.call(&args).await.unwrap(), 3.0);
+
+    let err = test_tool(&SearchTool, json!({ "query": "", "tags": [] })).await;
+    assert_eq!(err.unwrap_err(), "Query must not be empty");
+}
+
+#[tokio::test]
+async fn test_resource() {
+    let body = get("/resources/list").await;
+    assert_eq!(body["resources"][0]["uri"], "ci://status");
+    assert_eq!(body["resources"][0]["name"], "Build status");
+    assert_eq!(
+        body["resources"][0]["description"],
+        "Status of the latest CI build."
+    );
+
+    let (status, body) = post("/resources/read", json!({ "uri": "ci://status" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "passing");
+}
+
+#[tokio::test]
+async fn test_prompt() {
+    let body = get("/prompts/list").await;
+    assert_eq!(body["prompts"][0]["name"], "code_review");
+    assert_eq!(
+        body["prompts"][0]["arguments"],
+        json!([
+            { "name": "code", "description": "The code to review", "required": true },
+            { "name": "focus", "required": false }
+        ])
+    );
+
+    let (status, body) = post(
+        "/prompts/get",
+        json!({ "name": "code_review", "arguments": { "code": "fn main() {}" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["messages"][0]["content"]["text"],
+        "Review for bugs:\nfn main() {}"
+    );
+}
+
+#[tokio::test]
+async fn test_functions_remain_callable() {
+    assert_eq!(
+        checked_divide(6.0, 2.0, &RequestContext::new()).unwrap(),
+        3.0
+    );
+    assert!(search(String::new(), None, vec![]).await.is_err());
+}
@@ -0,0 +1,145 @@
+//! Tests for tool output schemas and structured content.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, ServerConfig, Tool};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+/// Reports the weather; `broken` returns output that violates its schema.
+struct WeatherTool {
+    broken: bool,
+}
+
+#[async_trait]
+impl Tool for WeatherTool {
+    fn description(&self) -> &str {
+        "Current weather for a city"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "temperature": { "type": "number" },
+                "conditions": { "type": "string" }
+            },
+            "required": ["temperature", "conditions"]
+        }))
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        if self.broken {
+            Ok(json!({ "temperature": "warm" }))
+        } else {
+            Ok(json!({ "temperature": 22.5, "conditions": "sunny" }))
+        }
+    }
+}
+
+struct PlainTool;
+
+#[async_trait]
+impl Tool for PlainTool {
+    fn description(&self) -> &str {
+        "No output schema"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!("ok"))
+    }
+}
+
+fn app(validate_output: bool) -> Router {
+    McpServer::with_config(ServerConfig::new().with_output_validation(validate_output))
+        .tool("weather", WeatherTool { broken: false })
+        .unwrap()
+        .tool("broken_weather", WeatherTool { broken: true })
+        .unwrap()
+        .tool("plain", PlainTool)
+        .unwrap()
+        .router()
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn call(app: &Router, name: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "name": name }).to_string()))
+        .unwrap();
+    send(app, request).await
+}
+
+#[tokio::test]
+async fn test_output_schema_listed() {
+    let request = Request::builder()
+        .uri("/tools/list")
+        .body(Body::empty())
+        .unwrap();
+    let (_, body) = send(&app(true), request).await;
+
+    let tools = body["tools"].as_array().unwrap();
+    let find = |name: &str| tools.iter().find(|t| t["name"] == name).unwrap();
+    assert_eq!(
+        find("weather")["outputSchema"]["required"],
+        json!(["temperature", "conditions"])
+    );
+    assert!(find("plain").get("outputSchema").is_none());
+}
+
+#[tokio::test]
+async fn test_structured_content_with_text_fallback() {
+    let (status, body) = call(&app(true), "weather").await;
+    assert_eq!(status, StatusCode::OK);
+    let structured = json!({ "temperature": 22.5, "conditions": "sunny" });
+    assert_eq!(body["structuredContent"], structured);
+    let text: Value = serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(text, structured);
+
+    let (_, body) = call(&app(true), "plain").await;
+    assert!(body.get("structuredContent").is_none());
+}
+
+#[tokio::test]
+async fn test_invalid_output_rejected_when_validating() {
+    let (status, body) = call(&app(true), "broken_weather").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    let message = body["message"].as_str().unwrap();
+    assert!(message.contains("failed schema validation"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_invalid_output_passes_without_validation() {
+    let (status, body) = call(&app(false), "broken_weather").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["structuredContent"]["temperature"], "warm");
+}
+
+#[test]
+fn test_validation_defaults_to_debug_builds() {
+    assert_eq!(
+        ServerConfig::default().validate_output,
+        cfg!(debug_assertions)
+    );
+}
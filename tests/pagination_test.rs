@@ -0,0 +1,207 @@
+//! Tests for cursor-based pagination of list results.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, Prompt, ResourceTemplate, ServerConfig, Tool};
+use common::{initialize, send};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tower::util::ServiceExt;
+
+struct NoopTool;
+
+#[async_trait]
+impl Tool for NoopTool {
+    fn description(&self) -> &str {
+        "Does nothing"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(Value::Null)
+    }
+}
+
+struct NoopPrompt;
+
+#[async_trait]
+impl Prompt for NoopPrompt {
+    fn description(&self) -> &str {
+        "Says nothing"
+    }
+
+    fn arguments(&self) -> Value {
+        json!([])
+    }
+
+    async fn render(&self, _arguments: &Value) -> Result<String, String> {
+        Ok(String::new())
+    }
+}
+
+struct NoopTemplate;
+
+#[async_trait]
+impl ResourceTemplate for NoopTemplate {
+    fn name(&self) -> &str {
+        "Noop"
+    }
+
+    fn description(&self) -> &str {
+        "Reads nothing"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self, _variables: &HashMap<String, String>) -> Result<String, String> {
+        Ok(String::new())
+    }
+}
+
+const TOOLS: [&str; 5] = ["echo", "alpha", "delta", "charlie", "bravo"];
+
+fn server() -> McpServer {
+    let mut server = McpServer::with_config(ServerConfig::new().with_page_size(2));
+    for name in TOOLS {
+        server.register_tool(name, NoopTool).unwrap();
+        server.register_prompt(name, NoopPrompt).unwrap();
+    }
+    for template in ["b://{id}", "a://{id}", "c://{id}"] {
+        server
+            .register_resource_template(template, NoopTemplate)
+            .unwrap();
+    }
+    server
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn names(body: &Value, field: &str, key: &str) -> Vec<String> {
+    body[field]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item[key].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_rest_pages_are_sorted_and_complete() {
+    let app = server().router();
+
+    let (status, body) = get(&app, "/tools/list").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(names(&body, "tools", "name"), ["alpha", "bravo"]);
+    let cursor = body["nextCursor"].as_str().unwrap().to_string();
+
+    let (_, body) = get(&app, &format!("/tools/list?cursor={}", cursor)).await;
+    assert_eq!(names(&body, "tools", "name"), ["charlie", "delta"]);
+    let cursor = body["nextCursor"].as_str().unwrap().to_string();
+
+    let (_, body) = get(&app, &format!("/tools/list?cursor={}", cursor)).await;
+    assert_eq!(names(&body, "tools", "name"), ["echo"]);
+    assert!(body.get("nextCursor").is_none());
+}
+
+#[tokio::test]
+async fn test_resource_templates_paginate() {
+    let app = server().router();
+
+    let (_, body) = get(&app, "/resources/templates/list").await;
+    assert_eq!(
+        names(&body, "resourceTemplates", "uriTemplate"),
+        ["a://{id}", "b://{id}"]
+    );
+    let cursor = body["nextCursor"].as_str().unwrap();
+    let (_, body) = get(
+        &app,
+        &format!("/resources/templates/list?cursor={}", cursor),
+    )
+    .await;
+    assert_eq!(
+        names(&body, "resourceTemplates", "uriTemplate"),
+        ["c://{id}"]
+    );
+}
+
+#[tokio::test]
+async fn test_cursor_survives_removal() {
+    let server = server();
+    let handle = server.handle();
+    let app = server.router();
+
+    let (_, body) = get(&app, "/tools/list").await;
+    let cursor = body["nextCursor"].as_str().unwrap().to_string();
+
+    // Removing an item from an earlier page must not skip later ones
+    handle.remove_tool("alpha");
+    let (_, body) = get(&app, &format!("/tools/list?cursor={}", cursor)).await;
+    assert_eq!(names(&body, "tools", "name"), ["charlie", "delta"]);
+}
+
+#[tokio::test]
+async fn test_invalid_cursor_rejected() {
+    let (status, _) = get(&server().router(), "/tools/list?cursor=%21%21").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_jsonrpc_pagination() {
+    let app = server().router();
+    let session = initialize(&app).await;
+
+    let mut prompts = Vec::new();
+    let mut cursor = Value::Null;
+    loop {
+        let (_, body) = send(
+            &app,
+            "/mcp",
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "prompts/list",
+                "params": { "cursor": cursor }
+            }),
+            Some(&session),
+        )
+        .await;
+        prompts.extend(names(&body["result"], "prompts", "name"));
+        match body["result"].get("nextCursor") {
+            Some(next) => cursor = next.clone(),
+            None => break,
+        }
+    }
+    assert_eq!(prompts, ["alpha", "bravo", "charlie", "delta", "echo"]);
+
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/list",
+            "params": { "cursor": 7 }
+        }),
+        Some(&session),
+    )
+    .await;
+    assert_eq!(body["error"]["code"], -32602);
+}
@@ -0,0 +1,313 @@
+//! Tests for per-handler access policies.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::auth::{AccessPolicy, ApiKeyAuthenticator};
+use axum_mcp::{FnPrompt, FnTool, McpServer, Principal, ResourceTemplate, Tool, ToolError};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tower::util::ServiceExt;
+
+const ALICE: &str = "alice-key";
+const BOB: &str = "bob-key";
+
+fn echo_tool(description: &str) -> impl Tool {
+    FnTool::new(
+        description,
+        json!({ "type": "object" }),
+        |args: Value| async move { Ok::<_, ToolError>(args) },
+    )
+}
+
+struct UserResource;
+
+#[async_trait]
+impl ResourceTemplate for UserResource {
+    fn name(&self) -> &str {
+        "User"
+    }
+
+    fn description(&self) -> &str {
+        "A user record"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/json"
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String> {
+        Ok(json!({ "id": variables["id"] }).to_string())
+    }
+}
+
+/// Alice is an admin; Bob has no scopes.
+fn authenticator() -> ApiKeyAuthenticator {
+    ApiKeyAuthenticator::new()
+        .with_key(Principal::new("alice").with_scopes(["admin"]), ALICE)
+        .with_key("bob", BOB)
+}
+
+fn server() -> McpServer {
+    McpServer::new()
+        .tool_fn("echo", "Echo", json!({ "type": "object" }), |args| async {
+            Ok(args)
+        })
+        .unwrap()
+        .tool_with_policy(
+            "delete_user",
+            echo_tool("Delete a user"),
+            AccessPolicy::require_scopes(["admin"]),
+        )
+        .unwrap()
+        .resource_fn(
+            "db://public/motd",
+            "MOTD",
+            "Message of the day",
+            "text/plain",
+            || async { Ok("hello".to_string()) },
+        )
+        .unwrap()
+        .resource_fn(
+            "db://admin/stats",
+            "Stats",
+            "Usage statistics",
+            "text/plain",
+            || async { Ok("42".to_string()) },
+        )
+        .unwrap()
+        .resource_template("db://admin/users/{id}", UserResource)
+        .unwrap()
+        .with_resource_policy("db://admin/", AccessPolicy::require_scopes(["admin"]))
+        .prompt_fn("greet", "Greet", json!([]), |_| async {
+            Ok("hi".to_string())
+        })
+        .unwrap()
+        .prompt_with_policy(
+            "audit",
+            FnPrompt::new("Audit the logs", json!([]), |_| async {
+                Ok("audit".to_string())
+            }),
+            AccessPolicy::any_scope(["admin", "auditor"]),
+        )
+        .unwrap()
+}
+
+fn app() -> Router {
+    server().with_authenticator(authenticator()).router()
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    key: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("accept", "application/json")
+        .header("authorization", format!("Bearer {}", key))
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn names(list: &Value, field: &str, key: &str) -> Vec<String> {
+    let mut names: Vec<String> = list[field]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item[key].as_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_tool_policy() {
+    let app = app();
+
+    let (_, body) = send(&app, "GET", "/tools/list", ALICE, None).await;
+    assert_eq!(names(&body, "tools", "name"), ["delete_user", "echo"]);
+    let (_, body) = send(&app, "GET", "/tools/list", BOB, None).await;
+    assert_eq!(names(&body, "tools", "name"), ["echo"]);
+
+    let call = json!({ "name": "delete_user", "arguments": { "id": 7 } });
+    let (status, _) = send(&app, "POST", "/tools/call", ALICE, Some(call.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(&app, "POST", "/tools/call", BOB, Some(call)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], 403);
+}
+
+#[tokio::test]
+async fn test_resource_prefix_policy() {
+    let app = app();
+
+    let (_, body) = send(&app, "GET", "/resources/list", BOB, None).await;
+    assert_eq!(names(&body, "resources", "uri"), ["db://public/motd"]);
+    let (_, body) = send(&app, "GET", "/resources/templates/list", BOB, None).await;
+    assert!(body["resourceTemplates"].as_array().unwrap().is_empty());
+    let (_, body) = send(&app, "GET", "/resources/list", ALICE, None).await;
+    assert_eq!(
+        names(&body, "resources", "uri"),
+        ["db://admin/stats", "db://public/motd"]
+    );
+
+    for uri in ["db://admin/stats", "db://admin/users/7"] {
+        let read = json!({ "uri": uri });
+        let (status, _) = send(&app, "POST", "/resources/read", BOB, Some(read.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", uri);
+        let (status, _) = send(&app, "POST", "/resources/read", ALICE, Some(read)).await;
+        assert_eq!(status, StatusCode::OK, "{}", uri);
+    }
+    let read = json!({ "uri": "db://public/motd" });
+    let (status, _) = send(&app, "POST", "/resources/read", BOB, Some(read)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_resource_policy_sees_decoded_uri() {
+    let app = McpServer::new()
+        .resource_template("db://{id}/notes", UserResource)
+        .unwrap()
+        .with_resource_policy("db://admin/", AccessPolicy::require_scopes(["admin"]))
+        .with_authenticator(authenticator())
+        .router();
+
+    // Percent-encoding part of the prefix does not get around the policy
+    for uri in ["db://admin/notes", "db://%61dmin/notes"] {
+        let read = json!({ "uri": uri });
+        let (status, _) = send(&app, "POST", "/resources/read", BOB, Some(read)).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", uri);
+    }
+    let read = json!({ "uri": "db://public/notes" });
+    let (status, _) = send(&app, "POST", "/resources/read", BOB, Some(read)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_prompt_policy() {
+    let app = app();
+
+    let (_, body) = send(&app, "GET", "/prompts/list", BOB, None).await;
+    assert_eq!(names(&body, "prompts", "name"), ["greet"]);
+    let (_, body) = send(&app, "GET", "/prompts/list", ALICE, None).await;
+    assert_eq!(names(&body, "prompts", "name"), ["audit", "greet"]);
+
+    let get = json!({ "name": "audit" });
+    let (status, _) = send(&app, "POST", "/prompts/get", BOB, Some(get.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, "POST", "/prompts/get", ALICE, Some(get)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// Send a JSON-RPC request to `/mcp` as Bob, returning the session id and body.
+async fn rpc(
+    app: &Router,
+    session: Option<&str>,
+    method: &str,
+    params: Value,
+) -> (Option<String>, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("accept", "application/json")
+        .header("authorization", format!("Bearer {}", BOB));
+    if let Some(session) = session {
+        request = request.header("mcp-session-id", session);
+    }
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let session = response
+        .headers()
+        .get("mcp-session-id")
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (session, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_jsonrpc_forbidden() {
+    let app = app();
+    let init = json!({ "protocolVersion": "2025-06-18", "capabilities": {} });
+    let (session, _) = rpc(&app, None, "initialize", init).await;
+    let session = session.as_deref();
+
+    let (_, body) = rpc(&app, session, "tools/list", json!({})).await;
+    assert_eq!(names(&body["result"], "tools", "name"), ["echo"]);
+
+    for (method, params) in [
+        ("tools/call", json!({ "name": "delete_user" })),
+        ("resources/read", json!({ "uri": "db://admin/stats" })),
+        ("resources/subscribe", json!({ "uri": "db://admin/stats" })),
+        ("prompts/get", json!({ "name": "audit" })),
+    ] {
+        let (_, body) = rpc(&app, session, method, params).await;
+        assert_eq!(body["error"]["code"], -32003, "{}", method);
+    }
+}
+
+#[tokio::test]
+async fn test_custom_policy() {
+    let app = McpServer::new()
+        .tool_with_policy(
+            "bobs_tool",
+            echo_tool("Only for Bob"),
+            AccessPolicy::new(|ctx| ctx.principal().is_some_and(|p| p.id == "bob")),
+        )
+        .unwrap()
+        .with_authenticator(authenticator())
+        .router();
+
+    let call = json!({ "name": "bobs_tool", "arguments": {} });
+    let (status, _) = send(&app, "POST", "/tools/call", BOB, Some(call.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, "POST", "/tools/call", ALICE, Some(call)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_runtime_registration_policy() {
+    let server = server().with_authenticator(authenticator());
+    let handle = server.handle();
+    let app = server.router();
+
+    handle
+        .add_tool_with_policy("purge", echo_tool("Purge"), AccessPolicy::authenticated())
+        .unwrap();
+    let (_, body) = send(&app, "GET", "/tools/list", BOB, None).await;
+    assert_eq!(names(&body, "tools", "name"), ["echo", "purge"]);
+
+    // Replacing a restricted tool without a policy lifts the restriction
+    handle
+        .add_tool("delete_user", echo_tool("Delete a user"))
+        .unwrap();
+    let (_, body) = send(&app, "GET", "/tools/list", BOB, None).await;
+    assert_eq!(
+        names(&body, "tools", "name"),
+        ["delete_user", "echo", "purge"]
+    );
+}
+
+#[tokio::test]
+async fn test_scope_policies_deny_without_authentication() {
+    let app = server().router();
+    let (_, body) = send(&app, "GET", "/tools/list", "ignored", None).await;
+    assert_eq!(names(&body, "tools", "name"), ["echo"]);
+}
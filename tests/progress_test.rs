@@ -0,0 +1,251 @@
+//! Tests for progress notifications reported through `RequestContext`.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{transport::stdio, McpServer, RequestContext, Tool};
+use common::initialize;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tower::util::ServiceExt;
+
+/// Reports progress 1, 1 (dropped as non-increasing), 2 and 3 out of 3.
+struct CountingTool;
+
+#[async_trait]
+impl Tool for CountingTool {
+    fn description(&self) -> &str {
+        "Counts to three"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        for step in [1.0, 1.0, 2.0, 3.0] {
+            ctx.report_progress(step, Some(3.0), Some("counting"));
+        }
+        Ok(json!({ "count": 3 }))
+    }
+}
+
+fn server() -> McpServer {
+    McpServer::new().tool("count", CountingTool).unwrap()
+}
+
+fn call_request(meta: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "method": "tools/call",
+        "params": { "name": "count", "arguments": {}, "_meta": meta }
+    })
+}
+
+async fn post(
+    app: &Router,
+    session: Option<&str>,
+    accept: &str,
+    body: Value,
+) -> (Option<String>, String) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("accept", accept);
+    if let Some(session) = session {
+        request = request.header("mcp-session-id", session);
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let session_id = response
+        .headers()
+        .get("mcp-session-id")
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (session_id, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+fn sse_messages(body: &str) -> Vec<Value> {
+    body.lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .map(|data| serde_json::from_str(data).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_progress_streamed_before_response() {
+    let app = server().router();
+    let session = initialize(&app).await;
+
+    let (_, body) = post(
+        &app,
+        Some(&session),
+        "application/json, text/event-stream",
+        call_request(json!({ "progressToken": "tok-1" })),
+    )
+    .await;
+    let messages = sse_messages(&body);
+    assert_eq!(messages.len(), 4);
+
+    let progress: Vec<f64> = messages[..3]
+        .iter()
+        .map(|m| {
+            assert_eq!(m["method"], "notifications/progress");
+            assert_eq!(m["params"]["progressToken"], "tok-1");
+            assert_eq!(m["params"]["total"], 3.0);
+            assert_eq!(m["params"]["message"], "counting");
+            m["params"]["progress"].as_f64().unwrap()
+        })
+        .collect();
+    assert_eq!(progress, vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(messages[3]["id"], 7);
+    assert!(messages[3]["result"].is_object());
+}
+
+#[tokio::test]
+async fn test_no_progress_without_token() {
+    let app = server().router();
+    let session = initialize(&app).await;
+
+    let (_, body) = post(
+        &app,
+        Some(&session),
+        "application/json, text/event-stream",
+        call_request(json!({})),
+    )
+    .await;
+    let messages = sse_messages(&body);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["id"], 7);
+}
+
+#[tokio::test]
+async fn test_progress_dropped_for_json_responses() {
+    let app = server().router();
+    let session = initialize(&app).await;
+
+    let (_, body) = post(
+        &app,
+        Some(&session),
+        "application/json",
+        call_request(json!({ "progressToken": 1 })),
+    )
+    .await;
+    let response: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(response["id"], 7);
+    assert!(response["result"].is_object());
+}
+
+#[tokio::test]
+async fn test_progress_sent_on_get_stream_for_json_responses() {
+    let app = server().router();
+    let session = initialize(&app).await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri("/mcp")
+        .header("accept", "text/event-stream")
+        .header("mcp-session-id", &session)
+        .body(Body::empty())
+        .unwrap();
+    let mut stream = app.clone().oneshot(request).await.unwrap().into_body();
+
+    let (_, body) = post(
+        &app,
+        Some(&session),
+        "application/json",
+        call_request(json!({ "progressToken": "tok-2" })),
+    )
+    .await;
+    let response: Value = serde_json::from_str(&body).unwrap();
+    assert!(response["result"].is_object());
+
+    let mut progress = Vec::new();
+    while progress.len() < 3 {
+        let frame = tokio::time::timeout(Duration::from_secs(5), stream.frame())
+            .await
+            .expect("timed out waiting for progress")
+            .unwrap()
+            .unwrap();
+        let Ok(data) = frame.into_data() else {
+            continue;
+        };
+        for message in sse_messages(std::str::from_utf8(&data).unwrap()) {
+            assert_eq!(message["method"], "notifications/progress");
+            assert_eq!(message["params"]["progressToken"], "tok-2");
+            progress.push(message["params"]["progress"].as_f64().unwrap());
+        }
+    }
+    assert_eq!(progress, vec![1.0, 2.0, 3.0]);
+}
+
+#[tokio::test]
+async fn test_progress_over_stdio() {
+    let (mut input, server_input) = tokio::io::duplex(64 * 1024);
+    let (server_output, output) = tokio::io::duplex(64 * 1024);
+    let serve = tokio::spawn(stdio::serve_io(server(), server_input, server_output));
+    let mut lines = BufReader::new(output).lines();
+
+    let initialize = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+    });
+    input
+        .write_all(format!("{}\n", initialize).as_bytes())
+        .await
+        .unwrap();
+    lines.next_line().await.unwrap().unwrap();
+
+    let call = call_request(json!({ "progressToken": 42 }));
+    input
+        .write_all(format!("{}\n", call).as_bytes())
+        .await
+        .unwrap();
+    drop(input);
+    serve.await.unwrap().unwrap();
+
+    let mut received = Vec::new();
+    while let Some(line) = lines.next_line().await.unwrap() {
+        received.push(serde_json::from_str::<Value>(&line).unwrap());
+    }
+    assert_eq!(received.len(), 4);
+    assert!(received[..3]
+        .iter()
+        .all(|m| m["method"] == "notifications/progress" && m["params"]["progressToken"] == 42));
+    assert_eq!(received[3]["id"], 7);
+}
+
+#[tokio::test]
+async fn test_rest_call_ignores_progress() {
+    let app = server().router();
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({ "name": "count", "arguments": {} }).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,314 @@
+//! Tests for request metadata exposed to handlers through `RequestContext`.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    extract::Request,
+    http::StatusCode,
+    middleware::{self, Next},
+    response::Response,
+    Router,
+};
+use axum_mcp::{
+    McpServer, Principal, Prompt, RequestContext, Resource, ResourceContents, ServerConfig, Tool,
+};
+use common::initialize;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+/// Extension inserted by the test middleware next to the principal.
+#[derive(Clone)]
+struct Tenant(&'static str);
+
+/// Reports what it can see of the request.
+struct WhoAmITool;
+
+#[async_trait]
+impl Tool for WhoAmITool {
+    fn description(&self) -> &str {
+        "Describe the caller"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        Ok(json!({
+            "request_id": ctx.request_id(),
+            "principal": ctx.principal().map(|p| p.id.clone()),
+            "can_write": ctx.principal().is_some_and(|p| p.has_scope("write")),
+            "user_agent": ctx.headers().get("user-agent").map(|v| v.to_str().unwrap()),
+            "tenant": ctx.extensions().get::<Tenant>().map(|t| t.0),
+            "session_id": ctx.session_id(),
+            "remaining_ms": ctx.remaining().map(|r| r.as_millis() as u64),
+        }))
+    }
+}
+
+/// Returns the caller's id as the resource content.
+struct ProfileResource;
+
+#[async_trait]
+impl Resource for ProfileResource {
+    fn name(&self) -> &str {
+        "Profile"
+    }
+
+    fn description(&self) -> &str {
+        "The caller's profile"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Err("Reading a profile needs the request context".to_string())
+    }
+
+    async fn read_with_context(
+        &self,
+        uri: &str,
+        ctx: &RequestContext,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let principal = ctx.principal().ok_or("Not authenticated")?;
+        Ok(vec![ResourceContents::text(
+            uri,
+            self.mime_type(),
+            principal.id.clone(),
+        )])
+    }
+}
+
+/// Greets the caller by principal id.
+struct GreetingPrompt;
+
+#[async_trait]
+impl Prompt for GreetingPrompt {
+    fn description(&self) -> &str {
+        "Greet the caller"
+    }
+
+    fn arguments(&self) -> Value {
+        json!([])
+    }
+
+    async fn render(&self, _arguments: &Value) -> Result<String, String> {
+        Ok("Hello, stranger!".to_string())
+    }
+
+    async fn render_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<String, String> {
+        match ctx.principal() {
+            Some(principal) => Ok(format!("Hello, {}!", principal.id)),
+            None => self.render(&json!({})).await,
+        }
+    }
+}
+
+async fn authenticate(mut request: Request, next: Next) -> Response {
+    if request.headers().contains_key("authorization") {
+        request
+            .extensions_mut()
+            .insert(Principal::new("alice").with_scopes(["read", "write"]));
+        request.extensions_mut().insert(Tenant("acme"));
+    }
+    next.run(request).await
+}
+
+fn app() -> Router {
+    McpServer::with_config(ServerConfig::new().with_tool_timeout(Duration::from_secs(30)))
+        .tool("whoami", WhoAmITool)
+        .unwrap()
+        .resource("profile://me", ProfileResource)
+        .unwrap()
+        .prompt("greeting", GreetingPrompt)
+        .unwrap()
+        .router()
+        .layer(middleware::from_fn(authenticate))
+}
+
+async fn post(
+    app: &Router,
+    uri: &str,
+    headers: &[(&str, &str)],
+    body: Value,
+) -> (StatusCode, Option<String>, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("accept", "application/json");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let session = response
+        .headers()
+        .get("mcp-session-id")
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, session, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_rest_tool_sees_request() {
+    let app = app();
+    let (status, _, body) = post(
+        &app,
+        "/tools/call",
+        &[
+            ("authorization", "Bearer token"),
+            ("user-agent", "test-agent"),
+            ("x-request-id", "req-123"),
+        ],
+        json!({ "name": "whoami", "arguments": {} }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let seen: Value = serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(seen["request_id"], "req-123");
+    assert_eq!(seen["principal"], "alice");
+    assert_eq!(seen["can_write"], true);
+    assert_eq!(seen["user_agent"], "test-agent");
+    assert_eq!(seen["tenant"], "acme");
+    // The REST routes are sessionless
+    assert_eq!(seen["session_id"], Value::Null);
+    let remaining = seen["remaining_ms"].as_u64().unwrap();
+    assert!(remaining > 0 && remaining <= 30_000, "{}", remaining);
+}
+
+#[tokio::test]
+async fn test_request_id_assigned_when_missing() {
+    let app = app();
+    let (_, _, body) = post(
+        &app,
+        "/tools/call",
+        &[],
+        json!({ "name": "whoami", "arguments": {} }),
+    )
+    .await;
+    let seen: Value = serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert!(!seen["request_id"].as_str().unwrap().is_empty());
+    assert_eq!(seen["principal"], Value::Null);
+    assert_eq!(seen["tenant"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_jsonrpc_tool_sees_session_and_principal() {
+    let app = app();
+    let session = initialize(&app).await;
+
+    let (status, _, body) = post(
+        &app,
+        "/mcp",
+        &[
+            ("mcp-session-id", &session),
+            ("authorization", "Bearer token"),
+            ("x-request-id", "req-456"),
+        ],
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "whoami", "arguments": {} }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let text = body["result"]["content"][0]["text"].as_str().unwrap();
+    let seen: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(seen["session_id"], session.as_str());
+    assert_eq!(seen["principal"], "alice");
+    assert_eq!(seen["request_id"], "req-456");
+}
+
+#[tokio::test]
+async fn test_resource_sees_principal() {
+    let app = app();
+    let (status, _, body) = post(
+        &app,
+        "/resources/read",
+        &[("authorization", "Bearer token")],
+        json!({ "uri": "profile://me" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "alice");
+
+    let (status, _, body) = post(
+        &app,
+        "/resources/read",
+        &[],
+        json!({ "uri": "profile://me" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("Not authenticated"));
+}
+
+#[tokio::test]
+async fn test_prompt_sees_principal() {
+    let app = app();
+    let session = initialize(&app).await;
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "prompts/get",
+        "params": { "name": "greeting" }
+    });
+
+    let (_, _, body) = post(
+        &app,
+        "/mcp",
+        &[
+            ("mcp-session-id", &session),
+            ("authorization", "Bearer token"),
+        ],
+        request.clone(),
+    )
+    .await;
+    assert_eq!(
+        body["result"]["messages"][0]["content"]["text"],
+        "Hello, alice!"
+    );
+
+    let (_, _, body) = post(&app, "/mcp", &[("mcp-session-id", &session)], request).await;
+    assert_eq!(
+        body["result"]["messages"][0]["content"]["text"],
+        "Hello, stranger!"
+    );
+}
+
+#[test]
+fn test_detached_context_is_empty() {
+    let ctx = RequestContext::new();
+    assert!(ctx.request_id().is_none());
+    assert!(ctx.principal().is_none());
+    assert!(ctx.headers().is_empty());
+    assert!(ctx.session().is_none());
+    assert!(ctx.deadline().is_none());
+    assert!(ctx.remaining().is_none());
+}
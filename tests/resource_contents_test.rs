@@ -0,0 +1,223 @@
+//! Tests for binary and multi-part resource contents.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, Resource, ResourceContents, ResourceTemplate};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tower::util::ServiceExt;
+
+const PNG_HEADER: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+struct LogoResource;
+
+#[async_trait]
+impl Resource for LogoResource {
+    fn name(&self) -> &str {
+        "Logo"
+    }
+
+    fn description(&self) -> &str {
+        "The project logo"
+    }
+
+    fn mime_type(&self) -> &str {
+        "image/png"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Err("The logo is binary".to_string())
+    }
+
+    async fn read_contents(&self, uri: &str) -> Result<Vec<ResourceContents>, String> {
+        Ok(vec![ResourceContents::blob(uri, "image/png", PNG_HEADER)])
+    }
+}
+
+struct DirectoryResource;
+
+#[async_trait]
+impl Resource for DirectoryResource {
+    fn name(&self) -> &str {
+        "Docs"
+    }
+
+    fn description(&self) -> &str {
+        "Every file in the docs directory"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/markdown"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Err("Read the files one by one".to_string())
+    }
+
+    async fn read_contents(&self, _uri: &str) -> Result<Vec<ResourceContents>, String> {
+        Ok(vec![
+            ResourceContents::text("file:///docs/a.md", "text/markdown", "# A"),
+            ResourceContents::text("file:///docs/b.md", "text/markdown", "# B"),
+        ])
+    }
+}
+
+struct TextResource;
+
+#[async_trait]
+impl Resource for TextResource {
+    fn name(&self) -> &str {
+        "Text"
+    }
+
+    fn description(&self) -> &str {
+        "Plain text via the String API"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok("hello".to_string())
+    }
+}
+
+struct FailingResource;
+
+#[async_trait]
+impl Resource for FailingResource {
+    fn name(&self) -> &str {
+        "Failing"
+    }
+
+    fn description(&self) -> &str {
+        "Always fails to read"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Err("Disk unavailable".to_string())
+    }
+}
+
+struct AvatarTemplate;
+
+#[async_trait]
+impl ResourceTemplate for AvatarTemplate {
+    fn name(&self) -> &str {
+        "Avatar"
+    }
+
+    fn description(&self) -> &str {
+        "A user's avatar"
+    }
+
+    fn mime_type(&self) -> &str {
+        "image/png"
+    }
+
+    async fn read(&self, _variables: &HashMap<String, String>) -> Result<String, String> {
+        Err("Avatars are binary".to_string())
+    }
+
+    async fn read_contents(
+        &self,
+        uri: &str,
+        variables: &HashMap<String, String>,
+    ) -> Result<Vec<ResourceContents>, String> {
+        Ok(vec![ResourceContents::blob(
+            uri,
+            "image/png",
+            variables["user"].as_bytes(),
+        )])
+    }
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .resource("file:///logo.png", LogoResource)
+        .unwrap()
+        .resource("file:///docs", DirectoryResource)
+        .unwrap()
+        .resource("file:///hello.txt", TextResource)
+        .unwrap()
+        .resource("file:///failing", FailingResource)
+        .unwrap()
+        .resource_template("avatar://{user}", AvatarTemplate)
+        .unwrap()
+        .router()
+}
+
+async fn read(uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/resources/read")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "uri": uri }).to_string()))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_blob_contents() {
+    let (status, body) = read("file:///logo.png").await;
+    assert_eq!(status, StatusCode::OK);
+    let content = &body["contents"][0];
+    assert_eq!(content["uri"], "file:///logo.png");
+    assert_eq!(content["mimeType"], "image/png");
+    assert!(content.get("text").is_none());
+
+    let contents: ResourceContents = serde_json::from_value(content.clone()).unwrap();
+    assert_eq!(contents.decode_blob().unwrap(), PNG_HEADER);
+}
+
+#[tokio::test]
+async fn test_multiple_contents() {
+    let (status, body) = read("file:///docs").await;
+    assert_eq!(status, StatusCode::OK);
+    let contents = body["contents"].as_array().unwrap();
+    assert_eq!(contents.len(), 2);
+    assert_eq!(contents[0]["uri"], "file:///docs/a.md");
+    assert_eq!(contents[1]["text"], "# B");
+}
+
+#[tokio::test]
+async fn test_string_api_still_works() {
+    let (status, body) = read("file:///hello.txt").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["contents"],
+        json!([{ "uri": "file:///hello.txt", "mimeType": "text/plain", "text": "hello" }])
+    );
+}
+
+#[tokio::test]
+async fn test_failed_read() {
+    let (status, body) = read("file:///failing").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("Disk unavailable"));
+}
+
+#[tokio::test]
+async fn test_template_blob_contents() {
+    let (status, body) = read("avatar://alice").await;
+    assert_eq!(status, StatusCode::OK);
+    let contents: ResourceContents = serde_json::from_value(body["contents"][0].clone()).unwrap();
+    assert_eq!(contents.uri(), "avatar://alice");
+    assert_eq!(contents.decode_blob().unwrap(), b"alice");
+}
@@ -0,0 +1,233 @@
+//! Tests for resource templates.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, Resource, ResourceTemplate};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tower::util::ServiceExt;
+
+struct UserTemplate;
+
+#[async_trait]
+impl ResourceTemplate for UserTemplate {
+    fn name(&self) -> &str {
+        "User"
+    }
+
+    fn description(&self) -> &str {
+        "A user by id"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/json"
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String> {
+        let id = &variables["id"];
+        if id == "missing" {
+            return Err(format!("No user '{}'", id));
+        }
+        Ok(json!({ "id": id }).to_string())
+    }
+}
+
+struct PostTemplate;
+
+#[async_trait]
+impl ResourceTemplate for PostTemplate {
+    fn name(&self) -> &str {
+        "Post"
+    }
+
+    fn description(&self) -> &str {
+        "A post by a user"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String> {
+        Ok(format!("{} by {}", variables["post"], variables["id"]))
+    }
+}
+
+struct AdminResource;
+
+#[async_trait]
+impl Resource for AdminResource {
+    fn name(&self) -> &str {
+        "Admin"
+    }
+
+    fn description(&self) -> &str {
+        "The admin user"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok("exact".to_string())
+    }
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .resource_template("db://users/{id}", UserTemplate)
+        .unwrap()
+        .resource_template("db://users/{id}/posts/{post}", PostTemplate)
+        .unwrap()
+        .resource("db://users/admin", AdminResource)
+        .unwrap()
+        .router()
+}
+
+async fn read(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/resources/read")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "uri": uri }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_read_extracts_variables() {
+    let app = app();
+
+    let (status, body) = read(&app, "db://users/42").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["uri"], "db://users/42");
+    assert_eq!(body["contents"][0]["mimeType"], "application/json");
+    let text: Value = serde_json::from_str(body["contents"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(text["id"], "42");
+
+    let (status, body) = read(&app, "db://users/7/posts/hello%20world").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "hello world by 7");
+}
+
+#[tokio::test]
+async fn test_exact_resource_takes_precedence() {
+    let (status, body) = read(&app(), "db://users/admin").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "exact");
+}
+
+#[tokio::test]
+async fn test_unmatched_uri_and_handler_errors() {
+    let app = app();
+
+    let (status, _) = read(&app, "db://groups/1").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = read(&app, "db://users/missing").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_list_templates() {
+    let request = Request::builder()
+        .uri("/resources/templates/list")
+        .body(Body::empty())
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+
+    let templates = body["resourceTemplates"].as_array().unwrap();
+    assert_eq!(templates.len(), 2);
+    assert_eq!(templates[0]["uriTemplate"], "db://users/{id}");
+    assert_eq!(templates[0]["name"], "User");
+    assert_eq!(templates[0]["mimeType"], "application/json");
+    assert_eq!(templates[1]["uriTemplate"], "db://users/{id}/posts/{post}");
+}
+
+#[tokio::test]
+async fn test_templates_over_jsonrpc() {
+    let app = McpServer::new()
+        .resource_template("db://users/{id}", UserTemplate)
+        .unwrap()
+        .router();
+
+    let post = |body: Value, session: Option<String>| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("content-type", "application/json");
+        if let Some(session) = session {
+            request = request.header("mcp-session-id", session);
+        }
+        app.clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+    };
+
+    let response = post(
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+        }),
+        None,
+    )
+    .await
+    .unwrap();
+    let session = response.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert!(body["result"]["capabilities"]["resources"].is_object());
+
+    let response = post(
+        json!({"jsonrpc": "2.0", "id": 2, "method": "resources/templates/list"}),
+        Some(session.clone()),
+    )
+    .await
+    .unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(
+        body["result"]["resourceTemplates"][0]["uriTemplate"],
+        "db://users/{id}"
+    );
+
+    let response = post(
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "resources/read",
+            "params": { "uri": "db://nothing/here" }
+        }),
+        Some(session),
+    )
+    .await
+    .unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["error"]["code"], -32002);
+}
+
+#[test]
+fn test_invalid_template_rejected() {
+    assert!(McpServer::new()
+        .resource_template("db://users/{+id}", UserTemplate)
+        .is_err());
+    assert!(McpServer::new()
+        .resource_template("users/{id}", UserTemplate)
+        .is_err());
+}
@@ -0,0 +1,221 @@
+//! Tests for runtime registration through `ServerHandle`.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{transport::stdio, McpServer, Prompt, Resource, Tool};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines};
+use tower::util::ServiceExt;
+
+struct EchoTool;
+
+#[async_trait]
+impl Tool for EchoTool {
+    fn description(&self) -> &str {
+        "Echoes its arguments"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        Ok(arguments.clone())
+    }
+}
+
+struct NoteResource;
+
+#[async_trait]
+impl Resource for NoteResource {
+    fn name(&self) -> &str {
+        "Note"
+    }
+
+    fn description(&self) -> &str {
+        "A note"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok("remember".to_string())
+    }
+}
+
+struct GreetingPrompt;
+
+#[async_trait]
+impl Prompt for GreetingPrompt {
+    fn description(&self) -> &str {
+        "Say hello"
+    }
+
+    fn arguments(&self) -> Value {
+        json!([])
+    }
+
+    async fn render(&self, _arguments: &Value) -> Result<String, String> {
+        Ok("Hello!".to_string())
+    }
+}
+
+async fn call_tool(app: &Router, name: &str) -> StatusCode {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({ "name": name, "arguments": {} }).to_string(),
+        ))
+        .unwrap();
+    app.clone().oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_add_and_remove_tool_while_serving() {
+    let server = McpServer::new();
+    let handle = server.handle();
+    let app = server.router();
+
+    assert_eq!(call_tool(&app, "echo").await, StatusCode::NOT_FOUND);
+
+    handle.add_tool("echo", EchoTool).unwrap();
+    assert_eq!(call_tool(&app, "echo").await, StatusCode::OK);
+
+    let request = Request::builder()
+        .uri("/tools/list")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["tools"][0]["name"], "echo");
+
+    assert!(handle.remove_tool("echo"));
+    assert!(!handle.remove_tool("echo"));
+    assert_eq!(call_tool(&app, "echo").await, StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn test_invalid_names_rejected() {
+    let handle = McpServer::new().handle();
+    assert!(handle.add_tool("bad name", EchoTool).is_err());
+    assert!(handle.add_resource("no-scheme", NoteResource).is_err());
+    assert!(handle.add_prompt("", GreetingPrompt).is_err());
+}
+
+#[test]
+fn test_clone_does_not_share_registry() {
+    let server = McpServer::new();
+    let clone = server.clone();
+    server.handle().add_tool("echo", EchoTool).unwrap();
+    assert!(clone.capabilities().tools.is_none());
+}
+
+struct Client {
+    input: DuplexStream,
+    output: Lines<BufReader<DuplexStream>>,
+}
+
+impl Client {
+    async fn connect(server: McpServer) -> (Self, Value) {
+        let (input, server_input) = tokio::io::duplex(64 * 1024);
+        let (server_output, output) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(stdio::serve_io(server, server_input, server_output));
+        let mut client = Self {
+            input,
+            output: BufReader::new(output).lines(),
+        };
+        client
+            .send(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": "initialize",
+                "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+            }))
+            .await;
+        let response = client.recv().await;
+        (client, response)
+    }
+
+    async fn send(&mut self, message: Value) {
+        self.input
+            .write_all(format!("{}\n", message).as_bytes())
+            .await
+            .unwrap();
+    }
+
+    async fn recv(&mut self) -> Value {
+        let line = tokio::time::timeout(Duration::from_secs(5), self.output.next_line())
+            .await
+            .expect("timed out waiting for message")
+            .unwrap()
+            .expect("output closed");
+        serde_json::from_str(&line).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn test_list_changed_capabilities() {
+    let server = McpServer::new();
+    let _handle = server.handle();
+    let (_, response) = Client::connect(server).await;
+
+    let capabilities = &response["result"]["capabilities"];
+    assert_eq!(capabilities["tools"]["listChanged"], true);
+    assert_eq!(capabilities["resources"]["listChanged"], true);
+    assert_eq!(capabilities["prompts"]["listChanged"], true);
+
+    // Without a handle the lists are fixed
+    let server = McpServer::new().tool("echo", EchoTool).unwrap();
+    let (_, response) = Client::connect(server).await;
+    assert!(response["result"]["capabilities"]["tools"]
+        .get("listChanged")
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_list_changed_notifications() {
+    let server = McpServer::new();
+    let handle = server.handle();
+    let (mut client, _) = Client::connect(server).await;
+
+    handle.add_tool("echo", EchoTool).unwrap();
+    assert_eq!(
+        client.recv().await["method"],
+        "notifications/tools/list_changed"
+    );
+
+    handle.add_resource("notes://today", NoteResource).unwrap();
+    assert_eq!(
+        client.recv().await["method"],
+        "notifications/resources/list_changed"
+    );
+
+    handle.add_prompt("greeting", GreetingPrompt).unwrap();
+    assert_eq!(
+        client.recv().await["method"],
+        "notifications/prompts/list_changed"
+    );
+
+    client
+        .send(json!({ "jsonrpc": "2.0", "id": 1, "method": "resources/list" }))
+        .await;
+    let response = client.recv().await;
+    assert_eq!(response["result"]["resources"][0]["uri"], "notes://today");
+
+    // Removing something that was never registered is silent
+    assert!(!handle.remove_prompt("missing"));
+    assert!(handle.remove_prompt("greeting"));
+    let notification = client.recv().await;
+    assert_eq!(notification["method"], "notifications/prompts/list_changed");
+}
@@ -0,0 +1,200 @@
+//! Tests for shared state set with `McpServer::with_state`.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use axum_mcp::{McpServer, Prompt, RequestContext, Resource, ResourceContents, Tool};
+use common::{initialize, send};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Stand-in for a connection pool: counts the queries run against it.
+#[derive(Clone, Default)]
+struct Db(Arc<AtomicUsize>);
+
+impl Db {
+    fn query(&self) -> usize {
+        self.0.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+#[derive(Clone)]
+struct Greeting(&'static str);
+
+struct QueryTool;
+
+#[async_trait]
+impl Tool for QueryTool {
+    fn description(&self) -> &str {
+        "Run a query"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        let db = ctx.state::<Db>().ok_or("No database configured")?;
+        Ok(json!({ "queries": db.query() }))
+    }
+}
+
+struct QueryCountResource;
+
+#[async_trait]
+impl Resource for QueryCountResource {
+    fn name(&self) -> &str {
+        "Query count"
+    }
+
+    fn description(&self) -> &str {
+        "Number of queries run so far"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Err("Reading the query count needs the request context".to_string())
+    }
+
+    async fn read_with_context(
+        &self,
+        uri: &str,
+        ctx: &RequestContext,
+    ) -> Result<Vec<ResourceContents>, String> {
+        let db = ctx.state::<Db>().ok_or("No database configured")?;
+        let count = db.0.load(Ordering::SeqCst);
+        Ok(vec![ResourceContents::text(
+            uri,
+            self.mime_type(),
+            count.to_string(),
+        )])
+    }
+}
+
+struct GreetingPrompt;
+
+#[async_trait]
+impl Prompt for GreetingPrompt {
+    fn description(&self) -> &str {
+        "Greet the user"
+    }
+
+    fn arguments(&self) -> Value {
+        json!([])
+    }
+
+    async fn render(&self, _arguments: &Value) -> Result<String, String> {
+        Err("Needs the request context".to_string())
+    }
+
+    async fn render_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<String, String> {
+        let greeting = ctx.state::<Greeting>().ok_or("No greeting configured")?;
+        Ok(greeting.0.to_string())
+    }
+}
+
+fn server() -> McpServer {
+    McpServer::new()
+        .tool("query", QueryTool)
+        .unwrap()
+        .resource("db://query-count", QueryCountResource)
+        .unwrap()
+        .prompt("greeting", GreetingPrompt)
+        .unwrap()
+}
+
+fn query_count(body: &Value) -> Value {
+    let text = body["content"][0]["text"].as_str().unwrap();
+    serde_json::from_str::<Value>(text).unwrap()["queries"].clone()
+}
+
+#[tokio::test]
+async fn test_state_shared_across_handlers() {
+    let db = Db::default();
+    let app = server()
+        .with_state(db.clone())
+        .with_state(Greeting("Hello from state"))
+        .router();
+
+    let call = json!({ "name": "query", "arguments": {} });
+    let (status, body) = send(&app, "/tools/call", call.clone(), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(query_count(&body), 1);
+    let (_, body) = send(&app, "/tools/call", call, None).await;
+    assert_eq!(query_count(&body), 2);
+
+    // Every handler sees the same value
+    let (status, body) = send(
+        &app,
+        "/resources/read",
+        json!({ "uri": "db://query-count" }),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["contents"][0]["text"], "2");
+    assert_eq!(db.0.load(Ordering::SeqCst), 2);
+
+    let (status, body) = send(&app, "/prompts/get", json!({ "name": "greeting" }), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["messages"][0]["content"]["text"], "Hello from state");
+}
+
+#[tokio::test]
+async fn test_state_over_jsonrpc() {
+    let db = Db::default();
+    let app = server().with_state(db.clone()).router();
+
+    let session = initialize(&app).await;
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "query", "arguments": {} }
+        }),
+        Some(&session),
+    )
+    .await;
+    assert_eq!(query_count(&body["result"]), 1);
+    assert_eq!(db.0.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_missing_state() {
+    let app = server().router();
+
+    let (status, body) = send(&app, "/prompts/get", json!({ "name": "greeting" }), None).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("No greeting configured"));
+    assert!(RequestContext::new().state::<Db>().is_none());
+}
+
+#[tokio::test]
+async fn test_later_state_replaces_earlier() {
+    let app = server()
+        .with_state(Greeting("first"))
+        .with_state(Greeting("second"))
+        .router();
+
+    let (_, body) = send(&app, "/prompts/get", json!({ "name": "greeting" }), None).await;
+    assert_eq!(body["messages"][0]["content"]["text"], "second");
+}
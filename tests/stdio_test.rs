@@ -0,0 +1,259 @@
+//! Tests for the stdio (newline-delimited JSON-RPC) transport.
+
+use async_trait::async_trait;
+use axum_mcp::{extract_string, transport::stdio, McpServer, ServerConfig, Tool};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines};
+use tokio::sync::Notify;
+
+struct EchoTool;
+
+#[async_trait]
+impl Tool for EchoTool {
+    fn description(&self) -> &str {
+        "Echo back the input text"
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" } },
+            "required": ["text"]
+        })
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        let text = extract_string(arguments, "text")?;
+        Ok(json!({ "echoed": text }))
+    }
+}
+
+struct SlowTool;
+
+#[async_trait]
+impl Tool for SlowTool {
+    fn description(&self) -> &str {
+        "Sleeps for a while"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok(json!({}))
+    }
+}
+
+struct StdioClient {
+    input: DuplexStream,
+    output: Lines<BufReader<DuplexStream>>,
+    server: tokio::task::JoinHandle<Result<(), axum_mcp::McpError>>,
+}
+
+impl StdioClient {
+    fn start(server: McpServer) -> Self {
+        let (input, server_input) = tokio::io::duplex(64 * 1024);
+        let (server_output, output) = tokio::io::duplex(64 * 1024);
+        let server = tokio::spawn(stdio::serve_io(server, server_input, server_output));
+        Self {
+            input,
+            output: BufReader::new(output).lines(),
+            server,
+        }
+    }
+
+    async fn send(&mut self, message: &str) {
+        self.input.write_all(message.as_bytes()).await.unwrap();
+        self.input.write_all(b"\n").await.unwrap();
+    }
+
+    async fn recv(&mut self) -> Value {
+        let line = tokio::time::timeout(Duration::from_secs(5), self.output.next_line())
+            .await
+            .expect("timed out waiting for output")
+            .unwrap()
+            .expect("output closed");
+        serde_json::from_str(&line).unwrap()
+    }
+
+    async fn initialize(&mut self) {
+        self.send(
+            &json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": "initialize",
+                "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+            })
+            .to_string(),
+        )
+        .await;
+        let response = self.recv().await;
+        assert_eq!(response["id"], 0);
+        assert!(response["result"]["capabilities"]["tools"].is_object());
+        self.send(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .await;
+    }
+}
+
+fn server() -> McpServer {
+    McpServer::new()
+        .tool("echo", EchoTool)
+        .unwrap()
+        .tool("slow", SlowTool)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_stdio_round_trip() {
+    let mut client = StdioClient::start(server());
+    client.initialize().await;
+
+    client
+        .send(r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"echo","arguments":{"text":"hi"}}}"#)
+        .await;
+    let response = client.recv().await;
+    assert_eq!(response["id"], 1);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(text).unwrap()["echoed"], "hi");
+
+    drop(client.input);
+    client.server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_stdio_rejects_requests_before_initialize() {
+    let mut client = StdioClient::start(server());
+    client
+        .send(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+        .await;
+    let response = client.recv().await;
+    assert_eq!(response["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn test_stdio_parse_error_and_schema_validation() {
+    let mut client = StdioClient::start(server());
+    client.initialize().await;
+
+    client.send("{not json").await;
+    let response = client.recv().await;
+    assert_eq!(response["error"]["code"], -32700);
+
+    client
+        .send(r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"echo","arguments":{}}}"#)
+        .await;
+    let response = client.recv().await;
+    assert_eq!(response["id"], 2);
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn test_stdio_applies_tool_timeout() {
+    let server =
+        McpServer::with_config(ServerConfig::new().with_tool_timeout(Duration::from_millis(100)))
+            .tool("slow", SlowTool)
+            .unwrap();
+    let mut client = StdioClient::start(server);
+    client.initialize().await;
+
+    client
+        .send(r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"slow"}}"#)
+        .await;
+    let response = client.recv().await;
+    assert_eq!(response["id"], 1);
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("timed out"));
+}
+
+#[tokio::test]
+async fn test_stdio_handles_requests_concurrently() {
+    let server =
+        McpServer::with_config(ServerConfig::new().with_tool_timeout(Duration::from_secs(10)))
+            .tool("slow", SlowTool)
+            .unwrap()
+            .tool("echo", EchoTool)
+            .unwrap();
+    let mut client = StdioClient::start(server);
+    client.initialize().await;
+
+    client
+        .send(r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"slow"}}"#)
+        .await;
+    client
+        .send(r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#)
+        .await;
+
+    // The ping is answered while the slow tool is still running
+    let response = client.recv().await;
+    assert_eq!(response["id"], 2);
+}
+
+#[tokio::test]
+async fn test_stdio_server_request_round_trip() {
+    let server = server();
+    let sessions = server.session_manager();
+    let mut client = StdioClient::start(server);
+    client.initialize().await;
+
+    let session = sessions.sessions().pop().unwrap();
+    let pending = tokio::spawn(async move { session.request("roots/list", json!({})).await });
+
+    let request = client.recv().await;
+    assert_eq!(request["method"], "roots/list");
+    client
+        .send(
+            &json!({"jsonrpc": "2.0", "id": request["id"], "result": { "roots": [] }}).to_string(),
+        )
+        .await;
+    assert_eq!(pending.await.unwrap().unwrap(), json!({ "roots": [] }));
+
+    // The client's response is consumed, not answered
+    client
+        .send(r#"{"jsonrpc":"2.0","id":5,"method":"ping"}"#)
+        .await;
+    assert_eq!(client.recv().await["id"], 5);
+}
+
+#[tokio::test]
+async fn test_stdio_answers_in_flight_requests_after_eof() {
+    let started = Arc::new(Notify::new());
+    let notify = Arc::clone(&started);
+    let server = McpServer::new()
+        .tool_fn(
+            "nap",
+            "Sleeps briefly",
+            json!({ "type": "object" }),
+            move |_| {
+                let notify = Arc::clone(&notify);
+                async move {
+                    notify.notify_one();
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    Ok(json!({ "rested": true }))
+                }
+            },
+        )
+        .unwrap();
+    let mut client = StdioClient::start(server);
+    client.initialize().await;
+
+    client
+        .send(r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"nap"}}"#)
+        .await;
+    started.notified().await;
+    client.input.shutdown().await.unwrap();
+
+    // The call still completes and is answered before the server exits
+    let response = client.recv().await;
+    assert_eq!(response["id"], 1);
+    assert!(response["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("rested"));
+    client.server.await.unwrap().unwrap();
+}
@@ -0,0 +1,241 @@
+//! Tests for the Streamable HTTP transport and session handling.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+    Router,
+};
+use axum_mcp::{McpServer, ServerConfig, Tool};
+use common::initialize;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+struct NoopTool;
+
+#[async_trait]
+impl Tool for NoopTool {
+    fn description(&self) -> &str {
+        "Does nothing"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!({ "ok": true }))
+    }
+}
+
+fn app_with_config(config: ServerConfig) -> Router {
+    McpServer::with_config(config)
+        .tool("noop", NoopTool)
+        .unwrap()
+        .router()
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    session_id: Option<&str>,
+    accept: Option<&str>,
+    body: Option<Value>,
+) -> Response {
+    let mut request = Request::builder().method(method).uri("/mcp");
+    if let Some(id) = session_id {
+        request = request.header("mcp-session-id", id);
+    }
+    if let Some(accept) = accept {
+        request = request.header("accept", accept);
+    }
+    let body = match body {
+        Some(body) => {
+            request = request.header("content-type", "application/json");
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+    app.clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+fn tools_list() -> Value {
+    json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"})
+}
+
+async fn body_string(response: Response) -> String {
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_initialize_assigns_session_id() {
+    let app = app_with_config(ServerConfig::new());
+    let first = initialize(&app).await;
+    let second = initialize(&app).await;
+    assert!(!first.is_empty());
+    assert_ne!(first, second);
+}
+
+#[tokio::test]
+async fn test_failed_initialize_creates_no_session() {
+    let app = app_with_config(ServerConfig::new());
+    let response = send(
+        &app,
+        "POST",
+        None,
+        None,
+        Some(json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}})),
+    )
+    .await;
+    assert!(response.headers().get("mcp-session-id").is_none());
+    let body: Value = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(body["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn test_post_sse_response() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let response = send(
+        &app,
+        "POST",
+        Some(&session),
+        Some("application/json, text/event-stream"),
+        Some(tools_list()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+    let body = body_string(response).await;
+    let data = body
+        .lines()
+        .find_map(|line| line.strip_prefix("data: "))
+        .expect("SSE data line");
+    let message: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(message["id"], 2);
+    assert_eq!(message["result"]["tools"][0]["name"], "noop");
+    assert!(body.contains("event: message"));
+}
+
+#[tokio::test]
+async fn test_post_json_response() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let response = send(
+        &app,
+        "POST",
+        Some(&session),
+        Some("application/json"),
+        Some(tools_list()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/json");
+}
+
+#[tokio::test]
+async fn test_missing_and_unknown_session() {
+    let app = app_with_config(ServerConfig::new());
+
+    let response = send(&app, "POST", None, None, Some(tools_list())).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send(
+        &app,
+        "POST",
+        Some("no-such-session"),
+        None,
+        Some(tools_list()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_delete_ends_session() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let response = send(&app, "DELETE", Some(&session), None, None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = send(&app, "POST", Some(&session), None, Some(tools_list())).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send(&app, "DELETE", Some(&session), None, None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_session_expiry() {
+    let app = app_with_config(ServerConfig::new().with_session_timeout(Duration::from_millis(50)));
+    let session = initialize(&app).await;
+
+    let response = send(&app, "POST", Some(&session), None, Some(tools_list())).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let response = send(&app, "POST", Some(&session), None, Some(tools_list())).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_opens_event_stream() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let response = send(&app, "GET", Some(&session), Some("text/event-stream"), None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+    let response = send(&app, "GET", Some(&session), Some("application/json"), None).await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let response = send(&app, "GET", None, Some("text/event-stream"), None).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_unsupported_protocol_version_header() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session)
+        .header("mcp-protocol-version", "1999-01-01")
+        .body(Body::from(tools_list().to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_notifications_accepted() {
+    let app = app_with_config(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let response = send(
+        &app,
+        "POST",
+        Some(&session),
+        None,
+        Some(json!({"jsonrpc": "2.0", "method": "notifications/initialized"})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
@@ -0,0 +1,204 @@
+//! Tests for resource subscriptions and update notifications.
+
+use async_trait::async_trait;
+use axum_mcp::{transport::stdio, McpServer, Resource, ResourceNotifier, ResourceTemplate};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines};
+
+struct LiveResource {
+    subscribable: bool,
+}
+
+#[async_trait]
+impl Resource for LiveResource {
+    fn name(&self) -> &str {
+        "Build status"
+    }
+
+    fn description(&self) -> &str {
+        "Status of the latest build"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self) -> Result<String, String> {
+        Ok("passing".to_string())
+    }
+
+    fn subscribable(&self) -> bool {
+        self.subscribable
+    }
+}
+
+struct TicketTemplate;
+
+#[async_trait]
+impl ResourceTemplate for TicketTemplate {
+    fn name(&self) -> &str {
+        "Ticket"
+    }
+
+    fn description(&self) -> &str {
+        "A ticket by id"
+    }
+
+    fn mime_type(&self) -> &str {
+        "text/plain"
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<String, String> {
+        Ok(variables["id"].clone())
+    }
+
+    fn subscribable(&self) -> bool {
+        true
+    }
+}
+
+struct Client {
+    input: DuplexStream,
+    output: Lines<BufReader<DuplexStream>>,
+    next_id: i64,
+}
+
+impl Client {
+    async fn connect(server: McpServer) -> (Self, Value) {
+        let (input, server_input) = tokio::io::duplex(64 * 1024);
+        let (server_output, output) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(stdio::serve_io(server, server_input, server_output));
+        let mut client = Self {
+            input,
+            output: BufReader::new(output).lines(),
+            next_id: 1,
+        };
+        let result = client
+            .request(
+                "initialize",
+                json!({ "protocolVersion": "2025-06-18", "capabilities": {} }),
+            )
+            .await;
+        (client, result)
+    }
+
+    async fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.input
+            .write_all(format!("{}\n", message).as_bytes())
+            .await
+            .unwrap();
+        let response = self.recv().await;
+        assert_eq!(response["id"], id);
+        response
+    }
+
+    async fn recv(&mut self) -> Value {
+        let line = tokio::time::timeout(Duration::from_secs(5), self.output.next_line())
+            .await
+            .expect("timed out waiting for message")
+            .unwrap()
+            .expect("output closed");
+        serde_json::from_str(&line).unwrap()
+    }
+}
+
+fn server() -> (McpServer, ResourceNotifier) {
+    let server = McpServer::new();
+    let notifier = server.resource_notifier();
+    let server = server
+        .resource("ci://build", LiveResource { subscribable: true })
+        .unwrap()
+        .resource(
+            "ci://static",
+            LiveResource {
+                subscribable: false,
+            },
+        )
+        .unwrap()
+        .resource_template("tickets://{id}", TicketTemplate)
+        .unwrap();
+    (server, notifier)
+}
+
+#[tokio::test]
+async fn test_subscribe_capability_advertised_on_opt_in() {
+    let (_, response) = Client::connect(server().0).await;
+    assert_eq!(
+        response["result"]["capabilities"]["resources"]["subscribe"],
+        true
+    );
+
+    let plain = McpServer::new()
+        .resource(
+            "ci://static",
+            LiveResource {
+                subscribable: false,
+            },
+        )
+        .unwrap();
+    let (_, response) = Client::connect(plain).await;
+    assert!(response["result"]["capabilities"]["resources"]
+        .get("subscribe")
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_updates_reach_subscribers() {
+    let (server, notifier) = server();
+    let (mut client, _) = Client::connect(server).await;
+
+    // Not subscribed yet
+    assert_eq!(notifier.notify_updated("ci://build"), 0);
+
+    let response = client
+        .request("resources/subscribe", json!({ "uri": "ci://build" }))
+        .await;
+    assert!(response["result"].is_object());
+
+    assert_eq!(notifier.notify_updated("ci://build"), 1);
+    let notification = client.recv().await;
+    assert_eq!(notification["method"], "notifications/resources/updated");
+    assert_eq!(notification["params"]["uri"], "ci://build");
+
+    client
+        .request("resources/unsubscribe", json!({ "uri": "ci://build" }))
+        .await;
+    assert_eq!(notifier.notify_updated("ci://build"), 0);
+}
+
+#[tokio::test]
+async fn test_only_subscribed_sessions_are_notified() {
+    let (server, notifier) = server();
+    let (mut subscribed, _) = Client::connect(server.clone()).await;
+    let (_other, _) = Client::connect(server).await;
+
+    subscribed
+        .request("resources/subscribe", json!({ "uri": "tickets://7" }))
+        .await;
+    assert_eq!(notifier.notify_updated("tickets://7"), 1);
+    assert_eq!(notifier.notify_updated("tickets://8"), 0);
+    assert_eq!(subscribed.recv().await["params"]["uri"], "tickets://7");
+}
+
+#[tokio::test]
+async fn test_subscribe_errors() {
+    let (mut client, _) = Client::connect(server().0).await;
+
+    let response = client
+        .request("resources/subscribe", json!({ "uri": "ci://static" }))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    let response = client
+        .request("resources/subscribe", json!({ "uri": "ci://missing" }))
+        .await;
+    assert_eq!(response["error"]["code"], -32002);
+
+    let response = client.request("resources/subscribe", json!({})).await;
+    assert_eq!(response["error"]["code"], -32602);
+}
@@ -120,7 +120,7 @@ async fn test_tool_timeout() {
         "Timeout should occur around 30 seconds, but took {:?}",
         elapsed
     );
-    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
 
     let body = response.into_body().collect().await.unwrap().to_bytes();
     let json: Value = serde_json::from_slice(&body).unwrap();
@@ -0,0 +1,244 @@
+//! Tests for tool annotations and read-only mode.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, ServerConfig, Tool, ToolAnnotations, ToolFilter};
+use common::initialize;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+struct DeleteRecordTool;
+
+#[async_trait]
+impl Tool for DeleteRecordTool {
+    fn description(&self) -> &str {
+        "Delete a record by id"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    fn annotations(&self) -> Option<ToolAnnotations> {
+        Some(
+            ToolAnnotations::new()
+                .with_title("Delete Record")
+                .with_destructive_hint(true)
+                .with_idempotent_hint(true)
+                .with_open_world_hint(false),
+        )
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!({ "deleted": true }))
+    }
+}
+
+struct GetRecordTool;
+
+#[async_trait]
+impl Tool for GetRecordTool {
+    fn description(&self) -> &str {
+        "Fetch a record by id"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    fn annotations(&self) -> Option<ToolAnnotations> {
+        Some(ToolAnnotations::new().with_read_only_hint(true))
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!({ "id": 1 }))
+    }
+}
+
+struct PlainTool;
+
+#[async_trait]
+impl Tool for PlainTool {
+    fn description(&self) -> &str {
+        "No annotations"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!("ok"))
+    }
+}
+
+fn app(config: ServerConfig) -> Router {
+    McpServer::with_config(config)
+        .tool("delete_record", DeleteRecordTool)
+        .unwrap()
+        .tool("get_record", GetRecordTool)
+        .unwrap()
+        .tool("plain", PlainTool)
+        .unwrap()
+        .router()
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn post(uri: &str, body: Value, session: Option<&str>) -> Request<Body> {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json");
+    if let Some(session) = session {
+        request = request.header("mcp-session-id", session);
+    }
+    request.body(Body::from(body.to_string())).unwrap()
+}
+
+async fn call(app: &Router, name: &str) -> (StatusCode, Value) {
+    send(app, post("/tools/call", json!({ "name": name }), None)).await
+}
+
+#[tokio::test]
+async fn test_annotations_listed() {
+    let request = Request::builder()
+        .uri("/tools/list")
+        .body(Body::empty())
+        .unwrap();
+    let (_, body) = send(&app(ServerConfig::new()), request).await;
+
+    let tools = body["tools"].as_array().unwrap();
+    let find = |name: &str| tools.iter().find(|t| t["name"] == name).unwrap();
+    assert_eq!(
+        find("delete_record")["annotations"],
+        json!({
+            "title": "Delete Record",
+            "destructiveHint": true,
+            "idempotentHint": true,
+            "openWorldHint": false
+        })
+    );
+    assert_eq!(
+        find("get_record")["annotations"],
+        json!({ "readOnlyHint": true })
+    );
+    assert!(find("plain").get("annotations").is_none());
+}
+
+#[tokio::test]
+async fn test_all_tools_callable_by_default() {
+    let app = app(ServerConfig::new());
+    for name in ["delete_record", "get_record", "plain"] {
+        let (status, _) = call(&app, name).await;
+        assert_eq!(status, StatusCode::OK, "{}", name);
+    }
+}
+
+#[tokio::test]
+async fn test_read_only_mode_refuses_writes() {
+    let app = app(ServerConfig::new().with_read_only(true));
+
+    let (status, body) = call(&app, "delete_record").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["message"].as_str().unwrap().contains("read-only mode"));
+
+    // Without annotations a tool is assumed to write
+    let (status, _) = call(&app, "plain").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = call(&app, "get_record").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"][0]["text"], "{\"id\":1}");
+}
+
+#[tokio::test]
+async fn test_deny_destructive_filter() {
+    let app = app(ServerConfig::new().with_tool_filter(ToolFilter::deny_destructive()));
+
+    let (status, body) = call(&app, "delete_record").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["message"].as_str().unwrap().contains("destructive"));
+
+    // Without annotations a tool may be destructive
+    let (status, _) = call(&app, "plain").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = call(&app, "get_record").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_custom_tool_filter() {
+    let filter = ToolFilter::new(|annotations| annotations.open_world_hint == Some(false));
+    let app = app(ServerConfig::new().with_tool_filter(filter));
+
+    let (status, _) = call(&app, "delete_record").await;
+    assert_eq!(status, StatusCode::OK);
+    for name in ["get_record", "plain"] {
+        let (status, _) = call(&app, name).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", name);
+    }
+
+    // Turning read-only mode off removes only the read-only filter
+    let config = ServerConfig::new()
+        .with_read_only(true)
+        .with_read_only(false);
+    assert!(config.tool_filter.is_none());
+    let config = ServerConfig::new()
+        .with_tool_filter(ToolFilter::deny_destructive())
+        .with_read_only(false);
+    let filter = config.tool_filter.expect("custom filter kept");
+    assert!(!filter.allows(&ToolAnnotations::new()));
+    assert!(filter.allows(&ToolAnnotations::new().with_destructive_hint(false)));
+}
+
+#[tokio::test]
+async fn test_read_only_mode_over_jsonrpc() {
+    let app = app(ServerConfig::new().with_read_only(true));
+    let session = initialize(&app).await;
+
+    let (_, body) = send(
+        &app,
+        post(
+            "/mcp",
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "tools/call",
+                "params": { "name": "delete_record" }
+            }),
+            Some(&session),
+        ),
+    )
+    .await;
+    assert_eq!(body["error"]["code"], -32003);
+}
+
+#[test]
+fn test_hint_defaults() {
+    let unset = ToolAnnotations::new();
+    assert!(!unset.is_read_only());
+    assert!(unset.is_destructive());
+
+    let additive = ToolAnnotations::new().with_destructive_hint(false);
+    assert!(!additive.is_destructive());
+
+    // Read-only tools are never destructive, whatever the other hint says
+    let read_only = ToolAnnotations::new()
+        .with_read_only_hint(true)
+        .with_destructive_hint(true);
+    assert!(!read_only.is_destructive());
+}
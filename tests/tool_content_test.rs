@@ -0,0 +1,175 @@
+//! Tests for rich tool result content.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{
+    Annotations, Content, McpServer, RequestContext, ResourceContents, ResourceLink, Role, Tool,
+    ToolError, ToolResult,
+};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+const PNG_HEADER: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+struct ChartTool;
+
+#[async_trait]
+impl Tool for ChartTool {
+    fn description(&self) -> &str {
+        "Renders a chart"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn invoke(
+        &self,
+        _arguments: &Value,
+        _ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        Ok(ToolResult::text("Revenue by quarter")
+            .with_content(Content::image(PNG_HEADER, "image/png"))
+            .with_content(
+                ResourceLink::new("file:///data/revenue.csv", "revenue.csv")
+                    .with_description("Raw data")
+                    .with_mime_type("text/csv"),
+            ))
+    }
+}
+
+struct ReportTool;
+
+#[async_trait]
+impl Tool for ReportTool {
+    fn description(&self) -> &str {
+        "Returns a report with an embedded resource"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn invoke(
+        &self,
+        _arguments: &Value,
+        _ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        let resource = ResourceContents::text("file:///report.md", "text/markdown", "# Report");
+        Ok(Content::resource(resource)
+            .with_annotations(
+                Annotations::new()
+                    .with_audience([Role::User, Role::Assistant])
+                    .with_priority(0.9),
+            )
+            .into())
+    }
+}
+
+struct PlainTool;
+
+#[async_trait]
+impl Tool for PlainTool {
+    fn description(&self) -> &str {
+        "Returns plain JSON"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Ok(json!({ "answer": 42 }))
+    }
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .tool("chart", ChartTool)
+        .unwrap()
+        .tool("report", ReportTool)
+        .unwrap()
+        .tool("plain", PlainTool)
+        .unwrap()
+        .router()
+}
+
+async fn call(name: &str) -> Value {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "name": name }).to_string()))
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_image_and_resource_link() {
+    let body = call("chart").await;
+    let content = body["content"].as_array().unwrap();
+    assert_eq!(content.len(), 3);
+    assert_eq!(
+        content[0],
+        json!({ "type": "text", "text": "Revenue by quarter" })
+    );
+    assert_eq!(content[1]["type"], "image");
+    assert_eq!(content[1]["mimeType"], "image/png");
+    assert_eq!(content[1]["data"], "iVBORw0KGgo=");
+    assert_eq!(
+        content[2],
+        json!({
+            "type": "resource_link",
+            "uri": "file:///data/revenue.csv",
+            "name": "revenue.csv",
+            "description": "Raw data",
+            "mimeType": "text/csv"
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_embedded_resource_with_annotations() {
+    let body = call("report").await;
+    assert_eq!(
+        body["content"][0],
+        json!({
+            "type": "resource",
+            "resource": {
+                "uri": "file:///report.md",
+                "mimeType": "text/markdown",
+                "text": "# Report"
+            },
+            "annotations": { "audience": ["user", "assistant"], "priority": 0.9 }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_plain_value_becomes_text() {
+    let body = call("plain").await;
+    assert_eq!(
+        body,
+        json!({ "content": [{ "type": "text", "text": "{\"answer\":42}" }] })
+    );
+}
+
+#[test]
+fn test_tool_result_round_trips() {
+    let result = ToolResult::new(vec![
+        Content::text("hi"),
+        Content::audio([1, 2, 3], "audio/wav"),
+        ResourceLink::new("db://users/1", "User 1").into(),
+    ]);
+    let json = serde_json::to_value(&result).unwrap();
+    let parsed: ToolResult = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, result);
+}
@@ -0,0 +1,114 @@
+//! Tests for reporting tool failures as `isError` results.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{http::StatusCode, Router};
+use axum_mcp::{McpServer, ServerConfig, Tool};
+use common::{initialize, send};
+use serde_json::{json, Value};
+
+struct FailingTool;
+
+#[async_trait]
+impl Tool for FailingTool {
+    fn description(&self) -> &str {
+        "Always fails"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        Some(json!({ "type": "object", "required": ["id"] }))
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        Err("Database is unreachable".to_string())
+    }
+}
+
+fn app(config: ServerConfig) -> Router {
+    McpServer::with_config(config)
+        .tool("failing", FailingTool)
+        .unwrap()
+        .router()
+}
+
+#[tokio::test]
+async fn test_rest_failure_is_result() {
+    let (status, body) = send(
+        &app(ServerConfig::new().with_tool_errors_as_results(true)),
+        "/tools/call",
+        json!({ "name": "failing" }),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    // Error results are exempt from output schema validation
+    assert_eq!(
+        body,
+        json!({
+            "content": [{ "type": "text", "text": "Database is unreachable" }],
+            "isError": true
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_legacy_rest_errors_by_default() {
+    let (status, body) = send(
+        &app(ServerConfig::new()),
+        "/tools/call",
+        json!({ "name": "failing" }),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        body["message"],
+        "Tool execution failed: Database is unreachable"
+    );
+}
+
+#[tokio::test]
+async fn test_jsonrpc_failure_is_result() {
+    // The switch only affects REST
+    let app = app(ServerConfig::new());
+    let session = initialize(&app).await;
+
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "failing" }
+        }),
+        Some(&session),
+    )
+    .await;
+    assert!(body.get("error").is_none());
+    assert_eq!(body["result"]["isError"], true);
+    assert_eq!(
+        body["result"]["content"][0]["text"],
+        "Database is unreachable"
+    );
+
+    // Protocol problems are still errors
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": { "name": "missing" }
+        }),
+        Some(&session),
+    )
+    .await;
+    assert_eq!(body["error"]["code"], -32602);
+}
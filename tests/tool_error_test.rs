@@ -0,0 +1,184 @@
+//! Tests for structured `ToolError` responses.
+
+mod common;
+
+use async_trait::async_trait;
+use axum::{http::StatusCode, Router};
+use axum_mcp::{McpServer, RequestContext, ServerConfig, Tool, ToolError, ToolResult};
+use common::{initialize, send};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Transfers funds, failing with a `ToolError` matching the `fail` argument.
+struct TransferTool;
+
+#[async_trait]
+impl Tool for TransferTool {
+    fn description(&self) -> &str {
+        "Transfer funds between accounts"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn invoke(
+        &self,
+        arguments: &Value,
+        _ctx: &RequestContext,
+    ) -> Result<ToolResult, ToolError> {
+        let amount = arguments
+            .get("amount")
+            .ok_or_else(|| ToolError::missing_parameter("amount"))?;
+        let amount = amount
+            .as_f64()
+            .ok_or_else(|| ToolError::invalid_type("amount", "number", "string"))?;
+        if amount <= 0.0 {
+            return Err(ToolError::invalid_value("amount", "must be positive"));
+        }
+        if amount > 1000.0 {
+            // Plain messages convert into execution failures
+            Err("Insufficient funds")?;
+        }
+        Ok(ToolResult::text(format!("Transferred {}", amount)))
+    }
+}
+
+struct SlowTool;
+
+#[async_trait]
+impl Tool for SlowTool {
+    fn description(&self) -> &str {
+        "Never finishes in time"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, _arguments: &Value) -> Result<Value, String> {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok(json!("done"))
+    }
+}
+
+fn app() -> Router {
+    McpServer::with_config(ServerConfig::new().with_tool_timeout(Duration::from_secs(1)))
+        .tool("transfer", TransferTool)
+        .unwrap()
+        .tool("slow", SlowTool)
+        .unwrap()
+        .router()
+}
+
+async fn transfer(arguments: Value) -> (StatusCode, Value) {
+    send(
+        &app(),
+        "/tools/call",
+        json!({ "name": "transfer", "arguments": arguments }),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_missing_parameter() {
+    let (status, body) = transfer(json!({})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "missing_parameter");
+    assert_eq!(body["message"], "Missing required parameter: amount");
+    assert!(body.get("details").is_none());
+}
+
+#[tokio::test]
+async fn test_invalid_type_and_value() {
+    let (status, body) = transfer(json!({ "amount": "ten" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "invalid_type");
+
+    let (status, body) = transfer(json!({ "amount": -5 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "invalid_value");
+    assert_eq!(
+        body["details"],
+        "Parameter 'amount' is invalid: must be positive"
+    );
+}
+
+#[tokio::test]
+async fn test_execution_failure() {
+    let (status, body) = transfer(json!({ "amount": 5000 })).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["error_type"], "execution_failed");
+    assert_eq!(body["message"], "Tool execution failed: Insufficient funds");
+
+    let (status, body) = transfer(json!({ "amount": 10 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"][0]["text"], "Transferred 10");
+}
+
+#[tokio::test]
+async fn test_timeout_is_gateway_timeout() {
+    let (status, body) = send(&app(), "/tools/call", json!({ "name": "slow" }), None).await;
+    assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+    assert_eq!(body["code"], 504);
+    assert_eq!(body["error_type"], "timeout");
+    assert!(body["message"].as_str().unwrap().contains("timed out"));
+}
+
+#[tokio::test]
+async fn test_jsonrpc_error_data() {
+    let app = app();
+    let session = initialize(&app).await;
+
+    let (_, body) = send(
+        &app,
+        "/mcp",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "transfer", "arguments": { "amount": 0 } }
+        }),
+        Some(&session),
+    )
+    .await;
+    assert_eq!(body["error"]["code"], -32602);
+    assert_eq!(
+        body["error"]["data"],
+        json!({
+            "error_type": "invalid_value",
+            "details": "Parameter 'amount' is invalid: must be positive"
+        })
+    );
+}
+
+#[test]
+fn test_error_types_are_stable() {
+    let cases = [
+        (ToolError::missing_parameter("x"), "missing_parameter"),
+        (
+            ToolError::invalid_type("x", "number", "string"),
+            "invalid_type",
+        ),
+        (ToolError::invalid_value("x", "too big"), "invalid_value"),
+        (ToolError::execution_failed("boom"), "execution_failed"),
+        (ToolError::timeout(5), "timeout"),
+    ];
+    for (err, expected) in cases {
+        assert_eq!(err.error_type(), expected);
+    }
+    assert_eq!(ToolError::TIMEOUT, "timeout");
+    assert_eq!(
+        ToolError::timeout_after(Duration::from_millis(250)),
+        ToolError::Timeout(1)
+    );
+    assert_eq!(
+        ToolError::timeout_after(Duration::from_secs(5)).to_string(),
+        "Execution timed out after 5 seconds"
+    );
+    assert_eq!(
+        ToolError::from("boom"),
+        ToolError::ExecutionFailed("boom".to_string())
+    );
+}
@@ -0,0 +1,190 @@
+//! Tests for typed tools.
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_mcp::{McpServer, RequestContext, Tool, ToolError, TypedTool};
+use http_body_util::BodyExt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+/// Arguments for resizing an image.
+#[derive(Deserialize, JsonSchema)]
+struct ResizeArgs {
+    /// Path of the image
+    path: String,
+    /// Scale factor in percent
+    percent: u8,
+    /// Output format
+    format: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ResizeOutput {
+    path: String,
+    percent: u8,
+}
+
+struct ResizeTool;
+
+#[async_trait]
+impl TypedTool for ResizeTool {
+    type Args = ResizeArgs;
+    type Output = ResizeOutput;
+
+    fn description(&self) -> &str {
+        "Resize an image"
+    }
+
+    async fn call(
+        &self,
+        args: ResizeArgs,
+        _ctx: &RequestContext,
+    ) -> Result<ResizeOutput, ToolError> {
+        let extension = args.format.unwrap_or_else(|| "png".to_string());
+        if args.path.is_empty() {
+            return Err(ToolError::invalid_value("path", "must not be empty"));
+        }
+        Ok(ResizeOutput {
+            path: format!("{}.resized.{}", args.path, extension),
+            percent: args.percent,
+        })
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct EmptyArgs {}
+
+struct StatsTool;
+
+#[async_trait]
+impl TypedTool for StatsTool {
+    type Args = EmptyArgs;
+    type Output = Value;
+
+    fn description(&self) -> &str {
+        "Server statistics"
+    }
+
+    fn output_schema(&self) -> Option<Value> {
+        Some(json!({ "type": "object", "required": ["uptime"] }))
+    }
+
+    async fn call(&self, _args: EmptyArgs, _ctx: &RequestContext) -> Result<Value, ToolError> {
+        Ok(json!({ "uptime": 42 }))
+    }
+}
+
+fn app() -> Router {
+    McpServer::new()
+        .tool("resize", ResizeTool)
+        .unwrap()
+        .tool("stats", StatsTool)
+        .unwrap()
+        .router()
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, Value) {
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+async fn call(name: &str, arguments: Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/tools/call")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({ "name": name, "arguments": arguments }).to_string(),
+        ))
+        .unwrap();
+    send(request).await
+}
+
+#[tokio::test]
+async fn test_schema_derived_from_args() {
+    let request = Request::builder()
+        .uri("/tools/list")
+        .body(Body::empty())
+        .unwrap();
+    let (_, body) = send(request).await;
+    let tool = body["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "resize")
+        .unwrap()
+        .clone();
+
+    let schema = &tool["inputSchema"];
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["path", "percent"]));
+    assert_eq!(schema["properties"]["path"]["type"], "string");
+    assert_eq!(
+        schema["properties"]["path"]["description"],
+        "Path of the image"
+    );
+    assert_eq!(schema["properties"]["percent"]["type"], "integer");
+    assert!(schema.get("$schema").is_none());
+}
+
+#[tokio::test]
+async fn test_typed_call() {
+    let (status, body) = call("resize", json!({ "path": "cat.jpg", "percent": 50 })).await;
+    assert_eq!(status, StatusCode::OK);
+    let output: Value = serde_json::from_str(body["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        output,
+        json!({ "path": "cat.jpg.resized.png", "percent": 50 })
+    );
+}
+
+#[tokio::test]
+async fn test_deserialize_error_is_tool_error() {
+    // 300 passes the schema (which has no upper bound) but not `u8`
+    let (status, body) = call("resize", json!({ "path": "cat.jpg", "percent": 300 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error_type"], "invalid_value");
+    assert!(body["message"].as_str().unwrap().contains("'percent'"));
+
+    let (status, body) = call("resize", json!({ "path": "", "percent": 10 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body["details"],
+        "Parameter 'path' is invalid: must not be empty"
+    );
+}
+
+#[tokio::test]
+async fn test_invoke_maps_serde_errors() {
+    let ctx = RequestContext::new();
+
+    let err = ResizeTool
+        .invoke(&json!({ "percent": 10 }), &ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err, ToolError::missing_parameter("path"));
+
+    let err = ResizeTool
+        .invoke(&json!({ "path": "a.png", "percent": "ten" }), &ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ToolError::invalid_type("percent", "u8", "string \"ten\"")
+    );
+}
+
+#[tokio::test]
+async fn test_output_schema_gives_structured_content() {
+    let (status, body) = call("stats", json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["structuredContent"], json!({ "uptime": 42 }));
+}
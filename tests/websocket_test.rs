@@ -0,0 +1,223 @@
+//! Tests for the WebSocket transport (`websocket` feature).
+
+use async_trait::async_trait;
+use axum_mcp::{ClientRequestError, McpServer, RequestContext, SessionManager, Tool};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+struct EchoTool;
+
+#[async_trait]
+impl Tool for EchoTool {
+    fn description(&self) -> &str {
+        "Echo back the input"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call(&self, arguments: &Value) -> Result<Value, String> {
+        Ok(arguments.clone())
+    }
+}
+
+/// Reports the upgrade request's `x-client` header and the session id.
+struct CallerTool;
+
+#[async_trait]
+impl Tool for CallerTool {
+    fn description(&self) -> &str {
+        "Describe the caller"
+    }
+
+    fn schema(&self) -> Value {
+        json!({ "type": "object" })
+    }
+
+    async fn call_with_context(
+        &self,
+        _arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Result<Value, String> {
+        Ok(json!({
+            "client": ctx.headers().get("x-client").map(|v| v.to_str().unwrap()),
+            "session_id": ctx.session_id(),
+        }))
+    }
+}
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Start a server on an ephemeral port and connect a WebSocket client to it.
+async fn connect() -> (Socket, Arc<SessionManager>) {
+    let server = McpServer::new()
+        .tool("echo", EchoTool)
+        .unwrap()
+        .tool("caller", CallerTool)
+        .unwrap();
+    let sessions = server.session_manager();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, server.router()).await });
+
+    let mut request = format!("ws://{}/mcp/ws", addr)
+        .into_client_request()
+        .unwrap();
+    request
+        .headers_mut()
+        .insert("x-client", "test-client".parse().unwrap());
+    let (socket, _) = connect_async(request).await.unwrap();
+    (socket, sessions)
+}
+
+async fn send(socket: &mut Socket, message: Value) {
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn recv(socket: &mut Socket) -> Value {
+    loop {
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("timed out waiting for message")
+            .expect("socket closed")
+            .unwrap();
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+async fn initialize(socket: &mut Socket) {
+    send(
+        socket,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": { "protocolVersion": "2025-06-18", "capabilities": {} }
+        }),
+    )
+    .await;
+    let response = recv(socket).await;
+    assert_eq!(response["result"]["protocolVersion"], "2025-06-18");
+    send(
+        socket,
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_websocket_round_trip() {
+    let (mut socket, _) = connect().await;
+    initialize(&mut socket).await;
+
+    send(
+        &mut socket,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "echo", "arguments": { "x": 1 } }
+        }),
+    )
+    .await;
+    let response = recv(&mut socket).await;
+    assert_eq!(response["id"], 1);
+    assert!(response["result"]["content"][0]["text"].is_string());
+
+    socket
+        .send(Message::Text("{oops".to_string()))
+        .await
+        .unwrap();
+    let response = recv(&mut socket).await;
+    assert_eq!(response["error"]["code"], -32700);
+}
+
+#[tokio::test]
+async fn test_websocket_context_sees_upgrade_request() {
+    let (mut socket, sessions) = connect().await;
+    initialize(&mut socket).await;
+
+    send(
+        &mut socket,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "caller", "arguments": {} }
+        }),
+    )
+    .await;
+    let response = recv(&mut socket).await;
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let seen: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(seen["client"], "test-client");
+    let session = sessions.sessions().pop().unwrap();
+    assert_eq!(seen["session_id"], session.id());
+}
+
+#[tokio::test]
+async fn test_websocket_server_notification() {
+    let (mut socket, sessions) = connect().await;
+    initialize(&mut socket).await;
+
+    assert_eq!(
+        sessions.notify_all("notifications/message", json!({ "level": "info" })),
+        1
+    );
+    let notification = recv(&mut socket).await;
+    assert_eq!(notification["method"], "notifications/message");
+    assert_eq!(notification["params"]["level"], "info");
+}
+
+#[tokio::test]
+async fn test_websocket_server_request() {
+    let (mut socket, sessions) = connect().await;
+    initialize(&mut socket).await;
+
+    let session = sessions.sessions().pop().unwrap();
+    let pending = tokio::spawn(async move { session.request("roots/list", json!({})).await });
+
+    let request = recv(&mut socket).await;
+    assert_eq!(request["method"], "roots/list");
+    send(
+        &mut socket,
+        json!({"jsonrpc": "2.0", "id": request["id"], "result": { "roots": [] }}),
+    )
+    .await;
+
+    let result = pending.await.unwrap().unwrap();
+    assert_eq!(result, json!({ "roots": [] }));
+}
+
+#[tokio::test]
+async fn test_websocket_close_ends_session() {
+    let (mut socket, sessions) = connect().await;
+    initialize(&mut socket).await;
+
+    let session = sessions.sessions().pop().unwrap();
+    let pending = tokio::spawn(async move { session.request("roots/list", json!({})).await });
+    let request = recv(&mut socket).await;
+    assert_eq!(request["method"], "roots/list");
+
+    socket.close(None).await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(5), pending)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(result, Err(ClientRequestError::Closed)));
+    assert!(sessions.sessions().is_empty());
+}